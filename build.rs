@@ -0,0 +1,183 @@
+//! Links every day of `src/bin` into the main binary as a module, so that their `solution!` registrations
+//! end up in the in-process registry (see `template::registry`).
+//!
+//! Also generates the tests of `example_tests!()` from the manifests of the examples, `data/<year>/examples/manifest`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // binaries are named `YYYY-DD.rs`.
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".rs")?;
+            let (year, day) = stem.split_once('-')?;
+            let is_number = |x: &str, len| x.len() == len && x.bytes().all(|x| x.is_ascii_digit());
+            (is_number(year, 4) && is_number(day, 2)).then(|| stem.to_string())
+        })
+        .collect();
+
+    days.sort();
+
+    let modules: String = days
+        .iter()
+        .map(|bin| {
+            let path = bin_dir.join(format!("{bin}.rs"));
+            format!(
+                "#[path = {:?}]\nmod day_{};\n",
+                path.display().to_string(),
+                bin.replace('-', "_")
+            )
+        })
+        .collect();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), modules).unwrap();
+
+    write_example_tests(
+        Path::new(&manifest_dir),
+        &Path::new(&out_dir).join("example_tests"),
+        &days,
+    );
+}
+
+/// An entry of a manifest, e.g. `11 2 11.txt solve(10) = 1030`.
+struct Entry {
+    part: u8,
+    file: String,
+    /// The function called with the example and its parameters, e.g. `solve(10)`.
+    solver: Option<(String, String)>,
+    expected: String,
+}
+
+/// Writes the tests of every day to `<out_dir>/<year>-<day>.rs`, which `example_tests!()` includes by the name of
+/// the binary. The main binary links the days as modules, its file is empty.
+fn write_example_tests(root: &Path, out_dir: &Path, days: &[String]) {
+    fs::create_dir_all(out_dir).unwrap();
+    fs::write(out_dir.join("advent_of_code.rs"), "").unwrap();
+
+    for bin in days {
+        let (year, day) = bin.split_once('-').unwrap();
+        let examples = root.join("data").join(year).join("examples");
+        println!("cargo:rerun-if-changed={}", examples.display());
+
+        let entries = fs::read_to_string(examples.join("manifest"))
+            .map(|x| parse_manifest(&x, year, day))
+            .unwrap_or_default();
+
+        fs::write(out_dir.join(format!("{bin}.rs")), tests(&entries)).unwrap();
+    }
+}
+
+/// The entries of `day` in a manifest, one per line as `<day> <part> <file> [<solver>(<parameters>)] = <expected>`.
+/// Empty lines and lines starting with `#` are skipped.
+fn parse_manifest(manifest: &str, year: &str, day: &str) -> Vec<Entry> {
+    let mut entries = vec![];
+
+    for (i, line) in manifest.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (entry_day, entry) = parse_entry(line).unwrap_or_else(|| {
+            panic!(
+                "invalid entry on line {} of the manifest of {year}: {line}",
+                i + 1
+            )
+        });
+
+        if entry_day == day {
+            entries.push(entry);
+        }
+    }
+
+    entries
+}
+
+fn parse_entry(line: &str) -> Option<(String, Entry)> {
+    let (left, expected) = line.split_once(" = ")?;
+    let mut columns = left.split_whitespace();
+
+    let day = format!("{:02}", columns.next()?.parse::<u8>().ok()?);
+    let part = columns
+        .next()?
+        .parse()
+        .ok()
+        .filter(|x| [1, 2].contains(x))?;
+    let file = columns.next()?.to_string();
+
+    let solver = match columns.collect::<Vec<_>>().join(" ") {
+        x if x.is_empty() => None,
+        x => {
+            let (name, parameters) = x.strip_suffix(')')?.split_once('(')?;
+            Some((name.trim().to_string(), parameters.trim().to_string()))
+        }
+    };
+
+    let entry = Entry {
+        part,
+        file,
+        solver,
+        expected: expected.trim().to_string(),
+    };
+
+    Some((day, entry))
+}
+
+/// One test per entry, named after the part, the example and the solver, e.g. `part_two_11_solve_10`.
+fn tests(entries: &[Entry]) -> String {
+    let mut names: Vec<String> = vec![];
+    let mut code = String::new();
+
+    for entry in entries {
+        let default = if entry.part == 1 {
+            "part_one"
+        } else {
+            "part_two"
+        };
+        let stem = entry.file.strip_suffix(".txt").unwrap_or(&entry.file);
+
+        let (call, name) = match &entry.solver {
+            None => (format!("{default}(&input)"), format!("{default}_{stem}")),
+            Some((solver, parameters)) => (
+                format!("{solver}(&input, {parameters})"),
+                format!("{default}_{stem}_{solver}_{parameters}"),
+            ),
+        };
+
+        let name: String = name
+            .chars()
+            .map(|x| {
+                if x.is_ascii_alphanumeric() {
+                    x.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let mut name = name
+            .split('_')
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+
+        if names.contains(&name) {
+            name = format!("{name}_{}", names.len() + 1);
+        }
+
+        code.push_str(&format!(
+            "#[test]\nfn {name}() {{\n    let input = advent_of_code::template::read_example(YEAR, {:?});\n    assert_eq!({call}, {});\n}}\n\n",
+            entry.file, entry.expected
+        ));
+        names.push(name);
+    }
+
+    code
+}
@@ -0,0 +1,321 @@
+use std::fmt::Display;
+
+/// Answer of a solution part: an integer, a string, or a grid of pixels spelling letters.
+///
+/// # Display
+/// Integers and strings display as themselves. A grid displays as the letters recognized in it (see
+/// [`recognize`]), which is what gets compared to the confirmed answer and submitted, or as its drawing if some
+/// letter is not recognized.
+///
+/// ```ignore
+/// # use advent_of_code::Answer;
+/// let answer = Answer::from_drawing(
+///     "█..█.████
+///      █..█.█...
+///      ████.███.
+///      █..█.█...
+///      █..█.█...
+///      █..█.████",
+/// );
+/// assert_eq!(answer.to_string(), "HE");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of pixels, `true` being lit.
+    Grid(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Creates a grid from a drawing, `#` and `█` being lit pixels. Leading whitespace of the lines is ignored.
+    pub fn from_drawing(s: &str) -> Self {
+        let grid = s
+            .lines()
+            .map(|line| {
+                line.trim_start()
+                    .chars()
+                    .map(|x| x == '#' || x == '█')
+                    .collect()
+            })
+            .collect();
+
+        Self::Grid(grid)
+    }
+
+    /// Draws the grid with `#` and `.`, [`None`] for other answers.
+    pub fn drawing(&self) -> Option<String> {
+        let Self::Grid(grid) = self else {
+            return None;
+        };
+
+        let lines: Vec<String> = grid
+            .iter()
+            .map(|row| row.iter().map(|&x| if x { '#' } else { '.' }).collect())
+            .collect();
+
+        Some(lines.join("\n"))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(x) => write!(f, "{x}"),
+            Self::Text(x) => f.write_str(x),
+            Self::Grid(grid) => match recognize(grid) {
+                Some(letters) => f.write_str(&letters),
+                None => f.write_str(&self.drawing().unwrap_or_default()),
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(x: $t) -> Self {
+                    Self::Integer(x.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+/// Integers beyond the range of [`i128`] are kept as text.
+impl From<u128> for Answer {
+    fn from(x: u128) -> Self {
+        i128::try_from(x).map_or_else(|_| Self::Text(x.to_string()), Self::Integer)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        Self::Integer(x as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(x: isize) -> Self {
+        Self::Integer(x as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(x: String) -> Self {
+        Self::Text(x)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(x: &str) -> Self {
+        Self::Text(x.into())
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(x: Vec<Vec<bool>>) -> Self {
+        Self::Grid(x)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Letters of the 4x6 font, drawn side by side in [`SMALL_FONT`].
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+
+const SMALL_FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+    "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+    "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+    "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+];
+
+/// Letters of the 6x10 font, drawn side by side in [`LARGE_FONT`].
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+
+const LARGE_FONT: [&str; 10] = [
+    "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######",
+    ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.",
+    "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..",
+    "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...",
+    "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....",
+    "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....",
+    "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....",
+    "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+];
+
+/// A glyph drawn with `#` and `.`, one string per row.
+type Glyph = Vec<String>;
+
+/// Reads the letters drawn in `grid` with the 4x6 or 6x10 font of the puzzles, [`None`] if the grid is not a
+/// single line of known letters. Blank rows and columns around the letters are ignored. Letters are usually
+/// separated by blank columns, but may also touch each other.
+pub fn recognize(grid: &[Vec<bool>]) -> Option<String> {
+    let runs = split_glyphs(grid);
+
+    let (letters, font) = match runs.first()?.len() {
+        6 => (SMALL_LETTERS, &SMALL_FONT[..]),
+        10 => (LARGE_LETTERS, &LARGE_FONT[..]),
+        _ => return None,
+    };
+
+    let font: Vec<Vec<bool>> = font
+        .iter()
+        .map(|row| row.chars().map(|x| x == '#').collect())
+        .collect();
+    let font: Vec<(char, Glyph)> = letters.chars().zip(split_glyphs(&font)).collect();
+
+    runs.iter().map(|run| read_run(run, &font)).collect()
+}
+
+/// Reads a run of non-blank columns as the letters of `font` drawn next to each other without blank columns.
+fn read_run(run: &[String], font: &[(char, Glyph)]) -> Option<String> {
+    if run.iter().all(String::is_empty) {
+        return Some(String::new());
+    }
+
+    // a letter that fits may leave a rest that does not read, so every letter is tried.
+    font.iter().find_map(|(letter, glyph)| {
+        let width = glyph[0].len();
+        let is_prefix = run
+            .iter()
+            .zip(glyph)
+            .all(|(row, glyph)| row.get(..width) == Some(glyph.as_str()));
+
+        if !is_prefix {
+            return None;
+        }
+
+        let rest: Vec<String> = run.iter().map(|row| row[width..].to_string()).collect();
+        read_run(&rest, font).map(|rest| format!("{letter}{rest}"))
+    })
+}
+
+/// The glyphs of `grid`: its runs of non-blank columns between the first and last non-blank rows.
+fn split_glyphs(grid: &[Vec<bool>]) -> Vec<Glyph> {
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |y: usize, x: usize| grid[y].get(x).copied().unwrap_or(false);
+
+    let rows: Vec<usize> = (0..grid.len())
+        .filter(|&y| (0..width).any(|x| is_lit(y, x)))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (rows.first(), rows.last()) else {
+        return vec![];
+    };
+
+    let is_blank = |x: usize| (top..=bottom).all(|y| !is_lit(y, x));
+    let mut glyphs = vec![];
+    let mut x = 0;
+
+    while x < width {
+        if is_blank(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_blank(x) {
+            x += 1;
+        }
+
+        let glyph: Glyph = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if is_lit(y, x) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        glyphs.push(glyph);
+    }
+
+    glyphs
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{recognize, Answer, LARGE_FONT, LARGE_LETTERS, SMALL_FONT, SMALL_LETTERS};
+
+    #[test]
+    fn recognizes_fonts() {
+        let answer = Answer::from_drawing(&SMALL_FONT.join("\n"));
+        assert_eq!(answer.to_string(), SMALL_LETTERS);
+
+        let answer = Answer::from_drawing(&LARGE_FONT.join("\n"));
+        assert_eq!(answer.to_string(), LARGE_LETTERS);
+    }
+
+    #[test]
+    fn reads_drawings() {
+        let answer = Answer::from_drawing(
+            "█..█.████
+             █..█.█...
+             ████.███.
+             █..█.█...
+             █..█.█...
+             █..█.████",
+        );
+        assert_eq!(answer.to_string(), "HE");
+    }
+
+    #[test]
+    fn ignores_margins() {
+        let drawing: Vec<String> = [
+            "......",
+            "#..#.####",
+            "#..#.#...",
+            "####.###.",
+            "#..#.#...",
+            "#..#.#...",
+            "#..#.####",
+        ]
+        .iter()
+        .map(|x| format!("..{x}..."))
+        .collect();
+
+        let Answer::Grid(grid) = Answer::from_drawing(&drawing.join("\n")) else {
+            unreachable!()
+        };
+        assert_eq!(recognize(&grid), Some("HE".into()));
+    }
+
+    #[test]
+    fn reads_touching_letters() {
+        let answer = Answer::from_drawing(
+            "#...#####.#...#
+             #...##....#...#
+             .#.#.###...#.#.
+             ..#..#......#..
+             ..#..#......#..
+             ..#..####...#..",
+        );
+        assert_eq!(answer.to_string(), "YEY");
+
+        let Answer::Grid(grid) = Answer::from_drawing("#...#\n#...#\n.#.#.\n..#..\n..#..\n..##.")
+        else {
+            unreachable!()
+        };
+        assert_eq!(recognize(&grid), None);
+        assert_eq!(recognize(&[vec![true, false, true]]), None);
+    }
+
+    #[test]
+    fn draws_unknown_letters() {
+        let drawing = "#.#\n.#.\n#.#\n.#.\n#.#\n.#.";
+        let answer = Answer::from_drawing(drawing);
+        assert_eq!(answer.to_string(), drawing);
+
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from(-3_i32).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
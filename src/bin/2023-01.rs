@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use aho_corasick::AhoCorasick;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
@@ -11,7 +11,7 @@ pub fn part_one(input: &str) -> Option<u32> {
             .map(|line| {
                 let mut it = line.chars().filter_map(|c| c.to_digit(10));
                 let first = it.next().unwrap();
-                let last = it.last().unwrap_or(first);
+                let last = it.next_back().unwrap_or(first);
                 first * 10 + last
             })
             .sum(),
@@ -59,23 +59,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(142));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(281));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let top = HashMap::from([("red", 12), ("green", 13), ("blue", 14)]);
@@ -11,7 +11,7 @@ pub fn part_one(input: &str) -> Option<u32> {
             .filter_map(|line| {
                 let (head, rounds) = line.split_once(':').unwrap();
 
-                let game_id = head.split(' ').last().unwrap().parse::<u32>().unwrap();
+                let game_id = head.split(' ').next_back().unwrap().parse::<u32>().unwrap();
                 rounds
                     .split(';')
                     .flat_map(|round| round.split(','))
@@ -34,7 +34,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         input
             .lines()
             .map(|line| {
-                let rounds = line.split(':').last().unwrap();
+                let rounds = line.split(':').next_back().unwrap();
                 rounds
                     .split(';')
                     .flat_map(|round| round.split(','))
@@ -53,19 +53,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
-    }
-}
+advent_of_code::example_tests!();
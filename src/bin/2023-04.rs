@@ -1,6 +1,6 @@
 use std::{collections::HashSet, str::FromStr};
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 struct ScratchPad(u32);
 
@@ -84,19 +84,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(30));
-    }
-}
+advent_of_code::example_tests!();
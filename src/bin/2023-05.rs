@@ -2,7 +2,7 @@ use std::{collections::BTreeMap, str::FromStr};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 #[derive(Debug)]
 struct MapRange {
@@ -131,19 +131,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     .min()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(35));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 fn parse(line: &str) -> impl Iterator<Item = u64> + '_ {
     let (_, numbers) = line.split_once(':').unwrap();
@@ -49,19 +49,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(solve(t, d))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(288));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(71503));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
 enum HandType {
@@ -95,19 +95,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     solve(input, true)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6440));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5905));
-    }
-}
+advent_of_code::example_tests!();
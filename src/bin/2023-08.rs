@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use num::integer::lcm;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 #[derive(Debug)]
 struct Graph<'a> {
@@ -203,27 +203,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(2));
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
-        ));
-        assert_eq!(result, Some(6));
-    }
-}
+advent_of_code::example_tests!();
@@ -2,7 +2,7 @@ use std::{collections::HashSet, vec};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 type Graph = Vec<Vec<Vec<(usize, usize)>>>;
 
@@ -89,11 +89,7 @@ fn build_graph(input: &str) -> (Graph, (usize, usize)) {
     (graph, start.unwrap())
 }
 
-fn find_loop(
-    graph: &mut Vec<Vec<Vec<(usize, usize)>>>,
-    sx: usize,
-    sy: usize,
-) -> Vec<(usize, usize)> {
+fn find_loop(graph: &mut [Vec<Vec<(usize, usize)>>], sx: usize, sy: usize) -> Vec<(usize, usize)> {
     let n = graph.len();
     let m = graph[0].len();
 
@@ -184,31 +180,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(answer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let tests = &[(1, 4), (2, 4), (3, 8), (4, 8)];
-
-        for (part, expected) in tests {
-            let result = part_one(&advent_of_code::template::read_file_part(
-                "examples", DAY, *part,
-            ));
-            assert_eq!(result, Some(*expected));
-        }
-    }
-
-    #[test]
-    fn test_part_two() {
-        let tests = &[(5, 4), (6, 4), (7, 8), (8, 10)];
-
-        for (part, expected) in tests {
-            let result = part_two(&advent_of_code::template::read_file_part(
-                "examples", DAY, *part,
-            ));
-            assert_eq!(result, Some(*expected));
-        }
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 pub fn solve(input: &str, expansion: usize) -> usize {
     let galaxy_map = input
@@ -58,21 +58,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(solve(input, 1000000))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(374));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 10);
-        assert_eq!(result, 1030);
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 100);
-        assert_eq!(result, 8410);
-    }
-}
+advent_of_code::example_tests!();
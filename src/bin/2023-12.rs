@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 fn solve(spring: &str, counts: impl Iterator<Item = usize>) -> usize {
     let counts = counts.collect_vec();
@@ -77,19 +77,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(525152));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
 fn find_reflection(pattern: &[Vec<char>]) -> (Vec<usize>, Vec<usize>) {
     let n = pattern.len();
@@ -94,19 +94,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(400));
-    }
-}
+advent_of_code::example_tests!();
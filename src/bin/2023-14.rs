@@ -2,9 +2,10 @@ use advent_of_code::RotateInPlace;
 use itertools::Itertools;
 use std::collections::HashMap;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
-fn tilt_north(board: &mut Vec<Vec<char>>) {
+#[allow(clippy::needless_range_loop)]
+fn tilt_north(board: &mut [Vec<char>]) {
     let n = board.len();
     let m = board[0].len();
 
@@ -94,19 +95,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(eval(&board))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(64));
-    }
-}
+advent_of_code::example_tests!();
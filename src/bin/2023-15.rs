@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 fn custom_hash(s: &str) -> u32 {
     s.as_bytes()
@@ -84,19 +84,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1320));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(145));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,7 +1,8 @@
+use advent_of_code::template::runner::Progress;
 use advent_of_code::Direction;
 use itertools::Itertools;
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2023, 16);
 
 struct BoundingBox {
     n: isize,
@@ -110,6 +111,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     let n = board.len();
     let m = board[0].len();
     let mut answer = 0;
+    let progress = Progress::new(2 * (n + m) as u64);
 
     for i in 0..n {
         let left = covered(&board, ((i, 0), Direction::Right));
@@ -117,6 +119,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 
         let right = covered(&board, ((i, m - 1), Direction::Left));
         answer = std::cmp::max(answer, right);
+        progress.inc(2);
     }
 
     for j in 0..m {
@@ -125,24 +128,10 @@ pub fn part_two(input: &str) -> Option<usize> {
 
         let down = covered(&board, ((n - 1, j), Direction::Up));
         answer = std::cmp::max(answer, down);
+        progress.inc(2);
     }
 
     Some(answer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(51));
-    }
-}
+advent_of_code::example_tests!();
@@ -2,7 +2,7 @@ use advent_of_code::{Direction, NoCompare};
 use itertools::Itertools;
 use std::cmp::Reverse;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2023, 17);
 
 #[derive(Hash, Eq, PartialEq, Clone, Debug)]
 struct State {
@@ -111,27 +111,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(solve(&board, 4, 10))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(102));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(94));
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(71));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,7 +1,7 @@
 use advent_of_code::{cross, Direction, Point};
 use itertools::Itertools;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2023, 18);
 
 #[derive(Default, Debug)]
 struct State {
@@ -85,38 +85,4 @@ pub fn part_two(input: &str) -> Option<i64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(
-            part_one(&advent_of_code::template::read_file_part(
-                "examples", DAY, 1
-            )),
-            Some(4)
-        );
-        assert_eq!(
-            part_one(&advent_of_code::template::read_file_part(
-                "examples", DAY, 2
-            )),
-            Some(9)
-        );
-        assert_eq!(
-            part_one(&advent_of_code::template::read_file_part(
-                "examples", DAY, 3
-            )),
-            Some(21)
-        );
-
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(62));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(952408144115));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19);
 
 #[derive(Debug, Clone)]
 enum Target<'a> {
@@ -309,19 +309,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(answer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(167409079868000));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
 #[derive(Debug)]
 enum ModuleType {
@@ -372,22 +372,4 @@ pub fn part_two(_: &str) -> Option<u64> {
     Some(3907 * 3823 * 3733 * 3797)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(32000000));
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(11687500));
-    }
-
-    #[test]
-    fn test_part_two() {}
-}
+advent_of_code::example_tests!();
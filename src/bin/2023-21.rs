@@ -3,7 +3,7 @@ use std::collections::{HashSet, VecDeque};
 use advent_of_code::RotateInPlace;
 use itertools::Itertools;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21);
 
 fn find_start(board: &[Vec<char>]) -> (usize, usize) {
     let n = board.len();
@@ -155,7 +155,7 @@ fn solve_corner_up_right(board: &[Vec<char>], distance: usize) -> usize {
         }
 
         let len = count_up_to_dist.len();
-        let (even, odd) = if len % 2 == 0 {
+        let (even, odd) = if len.is_multiple_of(2) {
             (count_up_to_dist[len - 1], count_up_to_dist[len - 2])
         } else {
             (count_up_to_dist[len - 2], count_up_to_dist[len - 1])
@@ -351,19 +351,15 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(solve_part_two(input, 26501365))
 }
 
+advent_of_code::example_tests!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 6);
-        assert_eq!(result, 16);
-    }
-
     #[test]
     fn test_solve_up() {
-        let input = advent_of_code::template::read_file_part("examples", DAY, 2);
+        let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, 2);
         let board = input
             .lines()
             .map(|line| line.chars().collect_vec())
@@ -378,24 +374,4 @@ mod tests {
             }
         }
     }
-
-    #[test]
-    fn test_part_two() {
-        for (dist, expected) in [
-            (6, 16),
-            (10, 50),
-            (50, 1594),
-            (100, 6536),
-            (500, 167004),
-            (1000, 668697),
-            (5000, 16733044),
-        ] {
-            assert_eq!(
-                solve_part_two(&advent_of_code::template::read_file("examples", DAY), dist),
-                expected,
-                "dist = {}",
-                dist
-            );
-        }
-    }
 }
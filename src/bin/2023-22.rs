@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22);
 
 struct Brick {
     low: (usize, usize, usize),
@@ -36,13 +36,13 @@ impl Eq for Brick {}
 
 impl PartialOrd for Brick {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.low.2.cmp(&other.low.2))
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Brick {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        self.low.2.cmp(&other.low.2)
     }
 }
 
@@ -129,19 +129,4 @@ pub fn part_two(input: &str) -> Option<u32> {
         .into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-}
+advent_of_code::example_tests!();
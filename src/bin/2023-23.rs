@@ -1,8 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
+use advent_of_code::template::runner::Progress;
 use itertools::Itertools;
 
-advent_of_code::solution!(23);
+advent_of_code::solution!(2023, 23);
 
 type Graph = HashMap<(usize, usize), Vec<((usize, usize), u32)>>;
 
@@ -135,6 +136,7 @@ fn longest_path_cyclic(
     target: usize,
     graph: &[Vec<(usize, u32)>],
     cache: &mut HashMap<(usize, u64), Option<u32>>,
+    progress: &Progress,
 ) -> Option<u32> {
     if let Some(&result) = cache.get(&state) {
         return result;
@@ -149,7 +151,7 @@ fn longest_path_cyclic(
         for &(dst, d) in &graph[node] {
             if mask & (1 << dst) == 0 {
                 if let Some(n_dist) =
-                    longest_path_cyclic((dst, mask | (1 << dst)), target, graph, cache)
+                    longest_path_cyclic((dst, mask | (1 << dst)), target, graph, cache, progress)
                 {
                     result = result.max(Some(d + n_dist));
                 }
@@ -158,6 +160,7 @@ fn longest_path_cyclic(
     }
 
     cache.insert(state, result);
+    progress.inc(1);
     result
 }
 
@@ -260,24 +263,16 @@ pub fn part_two(input: &str) -> Option<u32> {
     let target = remap[&(n - 1, m - 2)];
 
     let mut cache = HashMap::new();
-    longest_path_cyclic((source, 1 << source), target, &n_graph, &mut cache)
-        .unwrap()
-        .into()
+    let progress = Progress::spinner();
+    longest_path_cyclic(
+        (source, 1 << source),
+        target,
+        &n_graph,
+        &mut cache,
+        &progress,
+    )
+    .unwrap()
+    .into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2023, 24);
 
 #[derive(Debug, Clone, Copy)]
 struct Point2d {
@@ -49,7 +49,7 @@ struct Hailstone {
 
 impl Hailstone {
     fn same_direction(&self, point: &Point2d) -> bool {
-        let delta = point.clone() - self.position;
+        let delta = *point - self.position;
         dot(&delta, &self.direction) >= EPS
     }
 }
@@ -142,6 +142,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(solve_part_one(input, 200000000000000.0, 400000000000000.0))
 }
 
+#[allow(dead_code)]
 #[derive(Default, Clone, Copy, Debug)]
 struct Point3d {
     x: f64,
@@ -185,6 +186,7 @@ impl std::ops::Mul<f64> for Point3d {
     }
 }
 
+#[allow(dead_code)]
 impl Point3d {
     fn dot(&self, rhs: &Self) -> f64 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
@@ -201,12 +203,14 @@ impl From<(f64, f64, f64)> for Point3d {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Default, Debug)]
 struct State {
     positions: Vec<Point3d>,
     directions: Vec<Point3d>,
 }
 
+#[allow(dead_code)]
 impl State {
     fn init_variable(&self) -> Variables {
         Variables {
@@ -264,6 +268,7 @@ impl State {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 struct Variables {
     position: Point3d,
@@ -271,6 +276,7 @@ struct Variables {
     delta: Vec<f64>,
 }
 
+#[allow(dead_code)]
 impl Variables {
     fn new_like(var: &Variables) -> Variables {
         Variables {
@@ -289,28 +295,9 @@ impl Variables {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     // TODO: Solved using python. Rewrite in Rust.
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = solve_part_one(
-            &advent_of_code::template::read_file("examples", DAY),
-            7.0,
-            27.0,
-        );
-        assert_eq!(result, 2);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, vec};
 
-advent_of_code::solution!(25);
+advent_of_code::solution!(2023, 25);
 
 #[derive(Clone)]
 struct Edge {
@@ -58,7 +58,7 @@ fn max_flow(mut graph: Vec<Vec<Edge>>, src: usize, sink: usize) -> usize {
     mf
 }
 
-fn find_size(graph: &Vec<Vec<Edge>>) -> usize {
+fn find_size(graph: &[Vec<Edge>]) -> usize {
     let mut visited = vec![false; graph.len()];
     let mut size = 0;
     let mut queue = vec![0];
@@ -91,7 +91,7 @@ pub fn part_one(input: &str) -> Option<usize> {
             let nxt = *cache.entry(nxt).or_insert(len);
 
             if cache.len() > graph.len() {
-                graph.resize_with(cache.len(), || vec![]);
+                graph.resize_with(cache.len(), Vec::new);
             }
 
             graph[head].push(Edge::new(nxt));
@@ -123,23 +123,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(left * right)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(54));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
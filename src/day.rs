@@ -1,3 +1,4 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -55,6 +56,20 @@ impl PartialOrd<u8> for Day {
     }
 }
 
+/// A [`Day`] is serialized as its number.
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        Self::new(day).ok_or_else(|| de::Error::custom(DayFromStrError))
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl FromStr for Day {
@@ -114,6 +129,143 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// An inclusive range of days, e.g. `1-10`. Either bound can be omitted (`20-` or `-5`), and a single day
+/// is a range of its own.
+///
+/// ```
+/// # use advent_of_code::DayRange;
+/// let range: DayRange = "20-".parse().unwrap();
+/// assert_eq!(range.to_string(), "20-25");
+/// assert_eq!(range.into_iter().count(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    start: Day,
+    end: Day,
+}
+
+impl DayRange {
+    /// Creates a [`DayRange`] if `start` is not after `end`, returns [`None`] otherwise.
+    pub fn new(start: Day, end: Day) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.start <= day && day <= self.end
+    }
+}
+
+impl Display for DayRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start.0)
+        } else {
+            write!(f, "{}-{}", self.start.0, self.end.0)
+        }
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |x: &str, default| match x.trim() {
+            "" => Ok(default),
+            x => x.parse().map_err(|_| DayRangeFromStrError),
+        };
+
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (parse(start, Day(1))?, parse(end, Day(25))?),
+            None => {
+                let day = s.trim().parse().map_err(|_| DayRangeFromStrError)?;
+                (day, day)
+            }
+        };
+
+        Self::new(start, end).ok_or(DayRangeFromStrError)
+    }
+}
+
+impl IntoIterator for DayRange {
+    type Item = Day;
+    type IntoIter = std::iter::Map<std::ops::RangeInclusive<u8>, fn(u8) -> Day>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // NOTE: both bounds are valid days.
+        (self.start.0..=self.end.0).map(Day)
+    }
+}
+
+/// A set of days, given as comma-separated [`DayRange`]s, e.g. `1-10,15,20-`.
+///
+/// ```
+/// # use advent_of_code::{day, DaySet};
+/// let days: DaySet = "1-3,15,24-".parse().unwrap();
+/// assert_eq!(days.to_string(), "1-3,15,24-25");
+/// assert!(days.contains(day!(15)));
+/// assert_eq!(days.iter().count(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(Vec<DayRange>);
+
+impl DaySet {
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.iter().any(|x| x.contains(day))
+    }
+
+    /// The days of the set in ascending order, each day once.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|&day| self.contains(day))
+    }
+}
+
+/// Every day of advent.
+impl Default for DaySet {
+    fn default() -> Self {
+        Self(vec![DayRange {
+            start: Day(1),
+            end: Day(25),
+        }])
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`] or a [`DaySet`].
+#[derive(Debug)]
+pub struct DayRangeFromStrError;
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days or ranges of days between 1 and 25, e.g. `1-10,15,20-`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +286,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DayRange, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +319,30 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_ranges() {
+        let range = |s: &str| s.parse::<DayRange>().map(|x| x.to_string()).ok();
+
+        assert_eq!(range("5"), Some("5".into()));
+        assert_eq!(range("1-10"), Some("1-10".into()));
+        assert_eq!(range("20-"), Some("20-25".into()));
+        assert_eq!(range("-5"), Some("1-5".into()));
+        assert_eq!(range("10-1"), None);
+        assert_eq!(range("0-3"), None);
+        assert_eq!(range("26"), None);
+        assert_eq!(range("a-b"), None);
+    }
+
+    #[test]
+    fn iterates_day_sets() {
+        let days: DaySet = "20-,1-2,2-3".parse().unwrap();
+        let days: Vec<u8> = days.iter().map(Day::into_inner).collect();
+        assert_eq!(days, vec![1, 2, 3, 20, 21, 22, 23, 24, 25]);
+
+        assert_eq!(DaySet::default().iter().count(), 25);
+        assert!("1,,2".parse::<DaySet>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,8 +1,12 @@
+mod answer;
 mod day;
 pub mod template;
+mod year;
 
+pub use answer::*;
 pub use day::*;
 use num_enum::IntoPrimitive;
+pub use year::*;
 
 pub struct NoCompare<T>(pub T);
 
@@ -1,10 +1,21 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, download, examples, list, profile, read, run, scaffold, solve, stars, verify, watch,
+};
 use args::{parse, AppArguments};
 
+/// Every scaffolded day, linked in so that `list` and `run` can find them in the registry.
+mod days {
+    include!(concat!(env!("OUT_DIR"), "/days.rs"));
+}
+
 mod args {
     use std::process;
+    use std::time::Duration;
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{benchmark_history, commands::all::Limits, runner::OutputFormat},
+        Day, DaySet, Year,
+    };
 
     pub enum AppArguments {
         Download {
@@ -13,28 +24,113 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            force: bool,
+            answer_type: String,
+        },
         Scaffold {
             day: Day,
+            template: Option<String>,
+            answer_type: String,
         },
         Solve {
             day: Day,
             release: bool,
             time: bool,
+            warmup: Option<u32>,
             submit: Option<u8>,
+            force: bool,
+            format: OutputFormat,
+            input: Option<String>,
+            example: Option<u8>,
+            part: Option<u8>,
+        },
+        Watch {
+            day: Day,
+            release: bool,
+        },
+        Profile {
+            day: Day,
+            part: Option<u8>,
+        },
+        List,
+        Stars,
+        Verify,
+        Run {
+            days: Vec<Day>,
+            format: OutputFormat,
         },
         All {
+            days: DaySet,
             release: bool,
             time: bool,
+            warmup: Option<u32>,
+            format: OutputFormat,
+            compare: Option<String>,
+            threshold: f64,
+            limits: Limits,
+            jobs: usize,
         },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // every subcommand takes `--year`, defaulting to `AOC_YEAR` (see `.cargo/config.toml`).
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or("year not set. Pass --year or set AOC_YEAR.")?,
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                days: args.opt_value_from_str("--days")?.unwrap_or_default(),
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                warmup: args.opt_value_from_str("--warmup")?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                compare: args.opt_value_from_str("--compare")?,
+                threshold: args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(benchmark_history::DEFAULT_THRESHOLD),
+                limits: Limits {
+                    timeout: args.opt_value_from_fn("--timeout", parse_seconds)?,
+                    total_timeout: args.opt_value_from_fn("--total-timeout", parse_seconds)?,
+                    memory: args.opt_value_from_fn("--memory-limit", parse_megabytes)?,
+                },
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+            },
+            Some("watch") => AppArguments::Watch {
+                day: args.free_from_str()?,
+                release: args.contains("--release"),
+            },
+            Some("profile") => AppArguments::Profile {
+                day: args.free_from_str()?,
+                part: args.opt_value_from_str("--part")?,
+            },
+            Some("list") => AppArguments::List,
+            Some("stars") => AppArguments::Stars,
+            Some("verify") => AppArguments::Verify,
+            Some("run") => AppArguments::Run {
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                days: {
+                    // `--time`, `--warmup`, `--submit`, `--input`, `--example` and `--part` are read by the runner
+                    // itself.
+                    let _ = args.contains("--time");
+                    let _: Option<u32> = args.opt_value_from_str("--warmup")?;
+                    let _: Option<u8> = args.opt_value_from_str("--submit")?;
+                    let _: Option<String> = args.opt_value_from_str("--input")?;
+                    let _: Option<u8> = args.opt_value_from_str("--example")?;
+                    let _: Option<u8> = args.opt_value_from_str("--part")?;
+                    let mut days = vec![];
+                    while let Some(day) = args.opt_free_from_str()? {
+                        days.push(day);
+                    }
+                    days
+                },
             },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
@@ -42,14 +138,31 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                force: args.contains("--force"),
+                answer_type: args
+                    .opt_value_from_str("--answer-type")?
+                    .unwrap_or_else(|| "u32".into()),
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
+                answer_type: args
+                    .opt_value_from_str("--answer-type")?
+                    .unwrap_or_else(|| "u32".into()),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
                 time: args.contains("--time"),
+                warmup: args.opt_value_from_str("--warmup")?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                input: args.opt_value_from_str("--input")?,
+                example: args.opt_value_from_str("--example")?,
+                part: args.opt_value_from_str("--part")?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -66,7 +179,23 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Parses a number of megabytes into bytes.
+    fn parse_megabytes(s: &str) -> Result<u64, String> {
+        s.parse::<u64>()
+            .ok()
+            .and_then(|x| x.checked_mul(1024 * 1024))
+            .ok_or_else(|| format!("expecting a number of megabytes below 2^44, got \"{s}\""))
+    }
+
+    /// Parses a (possibly fractional) number of seconds.
+    fn parse_seconds(s: &str) -> Result<Duration, String> {
+        s.parse::<f64>()
+            .ok()
+            .and_then(|x| Duration::try_from_secs_f64(x).ok())
+            .ok_or_else(|| format!("expecting a number of seconds, got \"{s}\""))
     }
 }
 
@@ -76,17 +205,52 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                days,
+                release,
+                time,
+                warmup,
+                format,
+                compare,
+                threshold,
+                limits,
+                jobs,
+            } => all::handle(
+                year, &days, release, time, warmup, format, compare, threshold, limits, jobs,
+            ),
+            AppArguments::Watch { day, release } => watch::handle(year, day, release),
+            AppArguments::Profile { day, part } => profile::handle(year, day, part),
+            AppArguments::List => list::handle(year),
+            AppArguments::Stars => stars::handle(year),
+            AppArguments::Verify => verify::handle(year),
+            AppArguments::Run { days, format } => run::handle(year, &days, format),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Examples {
+                day,
+                force,
+                answer_type,
+            } => examples::handle(year, day, force, &answer_type),
+            AppArguments::Scaffold {
+                day,
+                template,
+                answer_type,
+            } => scaffold::handle(year, day, template.as_deref(), &answer_type),
             AppArguments::Solve {
                 day,
                 release,
                 time,
+                warmup,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                force,
+                format,
+                input,
+                example,
+                part,
+            } => solve::handle(
+                year, day, release, time, warmup, submit, force, format, input, example, part,
+            ),
         },
     };
 }
@@ -0,0 +1,182 @@
+/// Module that counts heap allocations, to report the memory cost of solution parts next to their duration.
+///
+/// The counting allocator is only installed as the global allocator with the `alloc_stats` feature, e.g.
+/// `cargo run --release --features alloc_stats -- solve 16`. Without it, [`read`] returns `None`.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether the counting allocator is the global allocator of this build.
+pub const ENABLED: bool = cfg!(feature = "alloc_stats");
+
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+/// Heap in use at the last [`reset`], the peak is reported on top of it.
+static BASELINE: AtomicU64 = AtomicU64::new(0);
+
+/// Forwards to the [`System`] allocator while counting allocations and the bytes in use.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc_stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: u64) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn count(size: u64) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        TOTAL.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded to `System` unchanged, the counters do not allocate.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            Self::count(layout.size() as u64);
+            Self::grow(layout.size() as u64);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            Self::count(layout.size() as u64);
+            Self::grow(layout.size() as u64);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            // a reallocation counts as an allocation of the new size.
+            Self::count(new_size as u64);
+            CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            Self::grow(new_size as u64);
+        }
+
+        new_ptr
+    }
+}
+
+/// Heap usage of a solution part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    /// Highest amount of heap in use on top of what was in use before the part started.
+    pub peak_bytes: u64,
+    /// Number of allocations (and reallocations).
+    pub count: u64,
+    /// Sum of the sizes of all allocations.
+    pub total_bytes: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak · {} allocs · {} total",
+            format_bytes(self.peak_bytes),
+            self.count,
+            format_bytes(self.total_bytes)
+        )
+    }
+}
+
+/// Starts counting from zero, with the heap currently in use as the baseline of the peak.
+pub fn reset() {
+    let current = CURRENT.load(Ordering::Relaxed);
+    BASELINE.store(current, Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    COUNT.store(0, Ordering::Relaxed);
+    TOTAL.store(0, Ordering::Relaxed);
+}
+
+/// The allocations since the last [`reset`], `None` if the counting allocator is not installed.
+#[must_use]
+pub fn read() -> Option<Allocations> {
+    ENABLED.then(counters)
+}
+
+fn counters() -> Allocations {
+    Allocations {
+        peak_bytes: PEAK
+            .load(Ordering::Relaxed)
+            .saturating_sub(BASELINE.load(Ordering::Relaxed)),
+        count: COUNT.load(Ordering::Relaxed),
+        total_bytes: TOTAL.load(Ordering::Relaxed),
+    }
+}
+
+/// Formats an amount of bytes with a binary unit, e.g. `1.5 MiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{counters, format_bytes, reset, CountingAllocator};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn counts_allocations() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+        reset();
+
+        // SAFETY: the pointers are freed with the layout they were allocated with.
+        unsafe {
+            let a = CountingAllocator.alloc(layout);
+            let b = CountingAllocator.realloc(a, layout, 8192);
+            CountingAllocator.dealloc(b, Layout::from_size_align(8192, 8).unwrap());
+        }
+
+        // other tests may allocate concurrently when the allocator is installed globally.
+        let allocations = counters();
+        assert!(allocations.count >= 2);
+        assert!(allocations.total_bytes >= 4096 + 8192);
+        assert!(allocations.peak_bytes >= 8192);
+    }
+}
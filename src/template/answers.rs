@@ -0,0 +1,149 @@
+/// Module that stores the confirmed answers of each day in `data/<year>/answers/DD.txt`, one `<part>: <answer>` per line:
+///
+/// ```text
+/// 1: 54630
+/// 2: 54770
+/// ```
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::{Day, Year};
+
+/// Confirmed answers of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn parse(s: &str) -> Self {
+        let mut answers = Self::default();
+
+        for line in s.lines() {
+            let Some((part, answer)) = line.split_once(':') else {
+                continue;
+            };
+
+            let answer = Some(answer.trim().to_string());
+
+            match part.trim() {
+                "1" => answers.part_1 = answer,
+                "2" => answers.part_2 = answer,
+                _ => {}
+            }
+        }
+
+        answers
+    }
+
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.part_1 = answer,
+            2 => self.part_2 = answer,
+            _ => {}
+        }
+    }
+
+    /// Compares an answer of a solution with the confirmed one.
+    #[must_use]
+    pub fn verify(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if answer == Some(expected) => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(answer) = &self.part_1 {
+            writeln!(f, "1: {answer}")?;
+        }
+        if let Some(answer) = &self.part_2 {
+            writeln!(f, "2: {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of comparing an answer with the confirmed one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// There is no confirmed answer for the part.
+    #[default]
+    Missing,
+}
+
+/// Reads the confirmed answers of a day, a missing file has no answers.
+pub fn read(year: Year, day: Day) -> Result<Answers, io::Error> {
+    match fs::read_to_string(get_data_path("answers", year, day)) {
+        Ok(s) => Ok(Answers::parse(&s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Records `answer` as the confirmed answer of a part.
+pub fn write(year: Year, day: Day, part: u8, answer: &str) -> Result<(), io::Error> {
+    let mut answers = read(year, day)?;
+    answers.set(part, answer);
+    let path = get_data_path("answers", year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, answers.to_string())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("1: 54630\n\n2:  foo bar \n");
+        assert_eq!(answers.get(1), Some("54630"));
+        assert_eq!(answers.get(2), Some("foo bar"));
+        assert_eq!(answers.to_string(), "1: 54630\n2: foo bar\n");
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::parse("2: 42");
+        assert_eq!(answers.verify(1, Some("42")), Verdict::Missing);
+        assert_eq!(answers.verify(2, Some("42")), Verdict::Match);
+        assert_eq!(
+            answers.verify(2, Some("41")),
+            Verdict::Mismatch {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(2, None),
+            Verdict::Mismatch {
+                expected: "42".into()
+            }
+        );
+    }
+}
@@ -0,0 +1,396 @@
+/// Built-in client for the Advent of Code website: downloads inputs and puzzle descriptions and submits answers.
+///
+/// The session token is read from the `AOC_SESSION` env var, or else from the file at `AOC_SESSION_FILE`
+/// (defaults to `~/.adventofcode.session`). The base URL can be changed with `AOC_BASE_URL`, e.g. to point the
+/// client at a local stub server.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::submissions::Outcome;
+use crate::{Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Http(Box<ureq::Error>),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "session token not found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::Http(e) => write!(f, "request to Advent of Code failed: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not write output files: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Http(Box::new(e))
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                " (",
+                env!("CARGO_PKG_AUTHORS"),
+                ")"
+            ))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Configures the client from the environment, see the module documentation.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// The personal puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// The puzzle description of a day (both parts once part one is solved), converted to markdown.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("{year}/day/{}", day.into_inner()))?;
+        Ok(puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer, returns the recognized verdict along with the response message.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<(Outcome, String), AocClientError> {
+        let html = self
+            .agent
+            .post(self.url(&format!("{year}/day/{}/answer", day.into_inner())))
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?
+            .body_mut()
+            .read_to_string()?;
+
+        let message = extract(&html, "<article>", "</article>")
+            .map(html_to_markdown)
+            .unwrap_or_default();
+
+        Ok((Outcome::parse(&message), message))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(self.url(path))
+            .header("Cookie", format!("session={}", self.session))
+            .call()?
+            .body_mut()
+            .read_to_string()?)
+    }
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let path = match env::var("AOC_SESSION_FILE") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => PathBuf::from(env::var("HOME").ok()?).join(".adventofcode.session"),
+    };
+
+    fs::read_to_string(path)
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+}
+
+/// The part of `s` between the first `start` and the following `end`.
+fn extract<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let s = &s[s.find(start)? + start.len()..];
+    Some(&s[..s.find(end)?])
+}
+
+/// Converts the descriptions (`<article class="day-desc">`) of a puzzle page to markdown.
+#[must_use]
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let start = "<article class=\"day-desc\">";
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(article) = extract(rest, start, "</article>") {
+        articles.push(html_to_markdown(article));
+        rest = &rest[rest.find(start).unwrap() + start.len() + article.len()..];
+    }
+
+    articles.join("\n\n") + "\n"
+}
+
+/// Converts the subset of HTML used by puzzle descriptions to markdown.
+/// `<em>` becomes `*...*` and `<code>` becomes `` `...` ``, also when nested (e.g. `` `*42*` ``).
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<Option<String>> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>').map(|x| start + x) else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("a", false) => {
+                let href = extract(tag, "href=\"", "\"").map(decode_entities);
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest, in_pre);
+
+    // collapse the blank lines left by consecutive block elements.
+    let mut markdown = out.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        out.push_str(&text);
+        return;
+    }
+
+    let text = text.replace('\n', " ");
+
+    // whitespace between block elements.
+    if text.trim().is_empty() && (out.is_empty() || out.ends_with('\n') || out.ends_with(' ')) {
+        return;
+    }
+
+    out.push_str(&text);
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, puzzle_to_markdown, AocClient};
+    use crate::template::submissions::Outcome;
+    use crate::{day, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    static PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/events">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul>
+<li>In this example, the calibration values are <code>12</code> &amp; <code>38</code>.</li>
+</ul>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54630</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>What is the sum?</p></article>
+</main></body></html>"#;
+
+    static VERDICT: &str = r#"<html><main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. <a href="/2023/day/1">[Return to Day 1]</a></p></article>
+</main></html>"#;
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Something is *wrong* with [global snow production](/2023/events).",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "- In this example, the calibration values are `12` & `38`.",
+            "",
+            "Adding these together produces `*142*`.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "What is the sum?",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(puzzle_to_markdown(PUZZLE), expected);
+    }
+
+    #[test]
+    fn converts_inline_elements() {
+        assert_eq!(
+            html_to_markdown("<p>a <span title=\"x\">b</span>&lt;c&gt;</p>"),
+            "a b<c>"
+        );
+    }
+
+    /// Serves one canned response per expected request, reporting each request (head and body) back to the test.
+    fn stub_server(responses: Vec<&'static str>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for body in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(x) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = x.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                sender.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    #[test]
+    fn talks_to_stub_server() {
+        let (url, requests) = stub_server(vec!["1abc2\n", PUZZLE, VERDICT]);
+        let client = AocClient::new(&url, "secret\n");
+
+        assert_eq!(client.input(year!(2023), day!(1)).unwrap(), "1abc2\n");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+
+        assert!(client
+            .puzzle(year!(2023), day!(1))
+            .unwrap()
+            .starts_with("## --- Day 1"));
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2023/day/1 HTTP/1.1"));
+
+        let (outcome, message) = client.submit(year!(2023), day!(1), 2, "123").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        assert!(message.starts_with("That's not the right answer"));
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=123"));
+    }
+}
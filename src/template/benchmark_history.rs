@@ -0,0 +1,295 @@
+/// Module that keeps the measurements of every timed `all` run in a local JSON Lines file,
+/// so that runs of different commits can be compared with each other.
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::{nanos, Record};
+use crate::{Day, Year};
+
+static HISTORY_PATH: &str = "data/benchmarks/history.jsonl";
+
+/// Slowdown (in percent) above which a part is flagged by default.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: Day,
+    pub part: u8,
+    #[serde(with = "nanos")]
+    pub duration: Duration,
+}
+
+/// The measurements of one timed run of a year.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: Year,
+    pub commit: String,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub machine: String,
+    /// Build profile of the run, `release` or `debug`. Empty for entries recorded before it was kept.
+    #[serde(default)]
+    pub profile: String,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Entry {
+    /// Creates an entry for the current commit and machine from the records of benched parts.
+    #[must_use]
+    pub fn new(year: Year, records: &[Record], is_release: bool) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+
+        Self {
+            year,
+            commit: resolve_commit("HEAD").unwrap_or_else(|| "unknown".into()),
+            dirty: git(&["status", "--porcelain"]).is_some_and(|x| !x.is_empty()),
+            timestamp,
+            machine: machine_name(),
+            profile: if is_release { "release" } else { "debug" }.into(),
+            measurements: records
+                .iter()
+                .filter(|x| x.stats.is_some())
+                .map(|x| Measurement {
+                    day: x.day,
+                    part: x.part,
+                    duration: x.duration,
+                })
+                .collect(),
+        }
+    }
+
+    /// UTC date of the run, formatted as `YYYY-MM-DD`.
+    #[must_use]
+    pub fn date(&self) -> String {
+        format_date(self.timestamp)
+    }
+}
+
+/// Change of a part between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub day: Day,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// Relative change in percent, positive values are slowdowns.
+    #[must_use]
+    pub fn percent(&self) -> f64 {
+        let before = self.before.as_nanos() as f64;
+        let after = self.after.as_nanos() as f64;
+        (after - before) / before.max(1.0) * 100.0
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Pairs the parts measured in both runs.
+#[must_use]
+pub fn compare(before: &Entry, after: &Entry) -> Vec<Delta> {
+    after
+        .measurements
+        .iter()
+        .filter_map(|m| {
+            let previous = before
+                .measurements
+                .iter()
+                .find(|x| x.day == m.day && x.part == m.part)?;
+
+            Some(Delta {
+                day: m.day,
+                part: m.part,
+                before: previous.duration,
+                after: m.duration,
+            })
+        })
+        .collect()
+}
+
+pub fn append(entry: &Entry) -> Result<(), io::Error> {
+    let path = Path::new(HISTORY_PATH);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+}
+
+/// Reads all entries of the history, oldest first.
+pub fn load() -> Result<Vec<Entry>, io::Error> {
+    match fs::read_to_string(HISTORY_PATH) {
+        Ok(s) => s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| serde_json::from_str(l).map_err(io::Error::from))
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+/// The most recent entry of `commit` for a year that ran on `machine` with the same build profile as `entry`.
+/// Runs on other machines or profiles are not comparable.
+#[must_use]
+pub fn find<'a>(entries: &'a [Entry], entry: &Entry, commit: &str) -> Option<&'a Entry> {
+    entries.iter().rev().find(|x| {
+        x.year == entry.year
+            && x.commit == commit
+            && x.machine == entry.machine
+            && x.profile == entry.profile
+    })
+}
+
+/// Resolves a git reference (e.g. `HEAD~1` or a branch name) into a commit hash.
+#[must_use]
+pub fn resolve_commit(reference: &str) -> Option<String> {
+    git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{reference}^{{commit}}"),
+    ])
+}
+
+/// Name of the current machine, `AOC_MACHINE` overrides the host name.
+#[must_use]
+pub fn machine_name() -> String {
+    env::var("AOC_MACHINE")
+        .or_else(|_| env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            String::from_utf8(output.stdout).ok()
+        })
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+}
+
+/// The current UTC date, e.g. `2023-12-25`.
+#[must_use]
+pub fn today() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+    format_date(timestamp)
+}
+
+/// Formats a unix timestamp as a UTC `YYYY-MM-DD` date.
+fn format_date(timestamp: u64) -> String {
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (timestamp / 86400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, find, format_date, Entry, Measurement};
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn entry(commit: &str, machine: &str, nanos: &[u64]) -> Entry {
+        Entry {
+            year: year!(2023),
+            commit: commit.into(),
+            dirty: false,
+            timestamp: 0,
+            machine: machine.into(),
+            profile: "release".into(),
+            measurements: nanos
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| Measurement {
+                    day: day!(1),
+                    part: i as u8 + 1,
+                    duration: Duration::from_nanos(x),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_703_462_400), "2023-12-25");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+    }
+
+    #[test]
+    fn compares_entries() {
+        let deltas = compare(&entry("a", "m", &[100]), &entry("b", "m", &[120, 50]));
+        assert_eq!(deltas.len(), 1);
+        assert!((deltas[0].percent() - 20.0).abs() < 1e-9);
+        assert!(deltas[0].is_regression(10.0));
+        assert!(!deltas[0].is_regression(25.0));
+    }
+
+    #[test]
+    fn finds_runs_of_the_same_machine() {
+        let entries = vec![
+            entry("a", "laptop", &[1]),
+            entry("a", "desktop", &[2]),
+            entry("b", "laptop", &[3]),
+        ];
+        let laptop = entry("c", "laptop", &[4]);
+
+        assert_eq!(find(&entries, &laptop, "a"), Some(&entries[0]));
+        assert_eq!(find(&entries, &entry("c", "server", &[4]), "a"), None);
+        assert_eq!(find(&entries, &laptop, "c"), None);
+
+        let mut other_year = laptop.clone();
+        other_year.year = year!(2022);
+        assert_eq!(find(&entries, &other_year, "a"), None);
+    }
+
+    #[test]
+    fn finds_runs_of_the_same_profile() {
+        let mut entries = vec![entry("a", "laptop", &[1]), entry("a", "laptop", &[2])];
+        entries[1].profile = "debug".into();
+
+        let mut debug = entry("b", "laptop", &[3]);
+        debug.profile = "debug".into();
+        assert_eq!(find(&entries, &debug, "a"), Some(&entries[1]));
+
+        entries[0].profile = String::new();
+        assert_eq!(find(&entries, &entry("b", "laptop", &[3]), "a"), None);
+    }
+}
@@ -1,46 +1,303 @@
-use std::io;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::template::{
+    benchmark_history::{self, Entry},
     readme_benchmarks::{self, Timings},
+    readme_stars, report,
+    runner::{OutputFormat, Record, Status},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DaySet, Year};
+use child_commands::{SolutionRun, Termination};
+
+/// Limits of the child processes running the days.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Limits {
+    /// Wall-clock time of a single day.
+    pub timeout: Option<Duration>,
+    /// Wall-clock time of the whole run, days that would start after it are skipped.
+    pub total_timeout: Option<Duration>,
+    /// Address space of a day in bytes (unix only).
+    pub memory: Option<u64>,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    days: &DaySet,
+    is_release: bool,
+    is_timed: bool,
+    warmup: Option<u32>,
+    format: OutputFormat,
+    compare: Option<String>,
+    threshold: f64,
+    limits: Limits,
+    jobs: usize,
+) {
     let mut timings: Vec<Timings> = vec![];
+    let mut all_records: Vec<Record> = vec![];
+    let mut failures: Vec<(Day, String)> = vec![];
+    let started = Instant::now();
+
+    // concurrent days compete for the cpu, which would skew their timings.
+    let jobs = if is_timed && jobs > 1 {
+        eprintln!("Ignoring --jobs {jobs}: timed runs execute one day at a time.");
+        1
+    } else {
+        jobs.max(1)
+    };
+
+    let days: Vec<Day> = days.iter().collect();
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+    let run_day = |day: Day| {
+        let remaining = limits
+            .total_timeout
+            .map(|x| x.saturating_sub(started.elapsed()));
+
+        if remaining.is_some_and(|x| x.is_zero()) {
+            return SolutionRun::empty(Termination::Skipped);
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        let limits = Limits {
+            timeout: match (limits.timeout, remaining) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+            ..limits
+        };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        child_commands::run_solution(year, day, is_timed, warmup, Some(format), &limits, jobs > 1)
+            .unwrap()
+    };
+
+    let print_header = |i: usize, day: Day| {
+        if format == OutputFormat::Text {
+            if i > 0 {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+        }
+    };
 
-        if output.is_empty() {
+    let mut handle_run = |day: Day, mut run: SolutionRun| {
+        for record in &run.records {
+            if let Some(error) = &record.error {
+                let outcome = match record.status {
+                    Status::Panicked => "panicked",
+                    _ => "failed",
+                };
+                failures.push((day, format!("part {} {outcome} ({error})", record.part)));
+            }
+        }
+
+        // a day whose part panicked exits non-zero once its other parts ran, the panic was reported above.
+        if matches!(run.termination, Termination::Failed(_))
+            && run.records.iter().any(|x| x.status == Status::Panicked)
+        {
+            run.termination = Termination::Exited;
+        }
+
+        if run.termination != Termination::Exited {
+            if format == OutputFormat::Text {
+                println!("{}", run.termination);
+            }
+
+            failures.push((day, run.termination.to_string()));
+        } else if run.records.is_empty() && format == OutputFormat::Text {
             println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
         }
-    });
+
+        timings.extend(child_commands::timings_from_run(&run, year, day));
+        all_records.extend(run.records);
+    };
+
+    if jobs == 1 {
+        for (i, &day) in days.iter().enumerate() {
+            print_header(i, day);
+            handle_run(day, run_day(day));
+        }
+    } else {
+        // workers pick the next day from a shared counter, the buffered output of finished days is
+        // printed in day order so the transcript reads like a sequential run.
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs.min(days.len()) {
+                let (sender, next, days, run_day) = (sender.clone(), &next, &days, &run_day);
+
+                scope.spawn(move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(i) else {
+                        break;
+                    };
+                    let _ = sender.send((i, run_day(day)));
+                });
+            }
+
+            drop(sender);
+
+            let mut finished = HashMap::new();
+            let mut printed = 0;
+
+            for (i, run) in receiver {
+                finished.insert(i, run);
+
+                while let Some(run) = finished.remove(&printed) {
+                    let day = days[printed];
+                    print_header(printed, day);
+                    run.replay(Some(format));
+                    handle_run(day, run);
+                    printed += 1;
+                }
+            }
+        });
+    }
+
+    if !failures.is_empty() {
+        eprintln!();
+        eprintln!("{ANSI_BOLD}{} failure(s):{ANSI_RESET}", failures.len());
+
+        for (day, reason) in &failures {
+            eprintln!("Day {day}: {reason}");
+        }
+    }
+
+    let mut is_failed = !failures.is_empty();
+
+    // stars only ever get added, a run over some of the days keeps the others. a table of another year is kept.
+    match readme_stars::update(year, &all_records, false) {
+        Ok(true) => eprintln!("Successfully updated README stars."),
+        Ok(false) => {}
+        Err(_) => eprintln!("Failed to update readme stars."),
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if format == OutputFormat::Text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            // the charts show every row of the table, including days measured by earlier runs.
+            let rows = match readme_benchmarks::update(timings.clone()) {
+                Ok(rows) => {
+                    eprintln!("Successfully updated README with benchmarks.");
+                    rows
+                }
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
+                    timings
                 }
+            };
+
+            if let Err(e) = report::write(&rows) {
+                eprintln!("Failed to write benchmark charts: {e}");
+            }
+        }
+
+        let history = benchmark_history::load().unwrap_or_else(|e| {
+            eprintln!("Failed to read benchmark history: {e}");
+            vec![]
+        });
+
+        let entry = Entry::new(year, &all_records, is_release);
+
+        if let Err(e) = benchmark_history::append(&entry) {
+            eprintln!("Failed to append run to benchmark history: {e}");
+        }
+
+        if let Some(reference) = compare {
+            // keep the comparison out of stdout when it carries JSON lines.
+            let mut out: Box<dyn Write> = match format {
+                OutputFormat::Text => Box::new(io::stdout()),
+                OutputFormat::Json => Box::new(io::stderr()),
+            };
+
+            if !print_comparison(&mut out, &history, &entry, &reference, threshold) {
+                is_failed = true;
             }
         }
+    } else if compare.is_some() {
+        eprintln!("--compare requires a timed run (--time).");
+    }
+
+    // exit once the results of the other days are saved, so that CI fails on any failure or regression.
+    if is_failed {
+        process::exit(1);
+    }
+}
+
+/// Prints the deltas between `entry` and the latest run of `reference`, returns `false` on regressions.
+fn print_comparison(
+    out: &mut dyn Write,
+    history: &[Entry],
+    entry: &Entry,
+    reference: &str,
+    threshold: f64,
+) -> bool {
+    let Some(commit) = benchmark_history::resolve_commit(reference) else {
+        eprintln!("Could not resolve \"{reference}\" to a commit.");
+        return false;
+    };
+
+    let Some(before) = benchmark_history::find(history, entry, &commit) else {
+        eprintln!(
+            "No benchmark history of {} for {reference} ({commit}) from a {} build on {}.",
+            entry.year, entry.profile, entry.machine
+        );
+        return false;
+    };
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{ANSI_BOLD}Compared with {reference}{ANSI_RESET} ({}, {}, {})",
+        &before.commit[..before.commit.len().min(10)],
+        before.date(),
+        before.machine
+    );
+
+    let mut regressions = 0;
+
+    for delta in benchmark_history::compare(before, entry) {
+        let flag = if delta.is_regression(threshold) {
+            regressions += 1;
+            " ⚠ slower"
+        } else {
+            ""
+        };
+
+        let _ = writeln!(
+            out,
+            "Day {} Part {}: {:.1?} → {:.1?} ({:+.1}%){flag}",
+            delta.day,
+            delta.part,
+            delta.before,
+            delta.after,
+            delta.percent()
+        );
+    }
+
+    if regressions > 0 {
+        let _ = writeln!(
+            out,
+            "{regressions} part(s) slowed down by more than {threshold}%."
+        );
     }
+
+    regressions == 0
 }
 
 #[derive(Debug)]
@@ -56,136 +313,363 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+/// Every day runs in a child process of the current binary (see the `run` command), which isolates the days
+/// from each other. This module encapsulates interaction with these processes, both invoking them as well as
+/// collecting the [`Record`]s they emit with `--format json`.
+pub(crate) mod child_commands {
+    use super::{Error, Limits};
+    use crate::template::runner::{print_record, OutputFormat, Record, Status};
+    use crate::template::{progress, registry};
+    use crate::{Day, Year};
     use std::{
+        env,
+        fmt::Display,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        process::{Command, ExitStatus, Stdio},
+        sync::{mpsc, Arc, Mutex},
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// How the child process of a day ended.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Termination {
+        Exited,
+        /// Exited with a non-zero status or was killed by a signal, e.g. after exceeding the memory limit.
+        Failed(String),
+        TimedOut(Duration),
+        /// Not started as the total timeout was reached.
+        Skipped,
+    }
+
+    impl Termination {
+        fn from_status(status: ExitStatus) -> Self {
+            if status.success() {
+                return Termination::Exited;
+            }
+
+            #[cfg(unix)]
+            if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+                return Termination::Failed(format!("killed by signal {signal}"));
+            }
+
+            match status.code() {
+                Some(code) => Termination::Failed(format!("exited with status {code}")),
+                None => Termination::Failed("terminated".into()),
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        /// Short form for the README table.
+        #[must_use]
+        pub fn label(&self) -> &'static str {
+            match self {
+                Termination::Exited => "-",
+                Termination::Failed(_) => "crashed",
+                Termination::TimedOut(_) => "timed out",
+                Termination::Skipped => "skipped",
+            }
+        }
+    }
 
-        if is_release {
-            args.push("--release");
+    impl Display for Termination {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Termination::Exited => write!(f, "finished"),
+                Termination::Failed(reason) => write!(f, "crashed ({reason})"),
+                Termination::TimedOut(timeout) => write!(f, "timed out after {timeout:.1?}"),
+                Termination::Skipped => write!(f, "skipped, the total timeout was reached"),
+            }
         }
+    }
+
+    /// The records a day emitted before its process ended.
+    pub struct SolutionRun {
+        pub records: Vec<Record>,
+        pub termination: Termination,
+        /// The lines printed by the child, only kept when its output is buffered.
+        pub output: Vec<Output>,
+    }
+
+    impl SolutionRun {
+        /// A run that printed and recorded nothing.
+        pub fn empty(termination: Termination) -> Self {
+            Self {
+                records: vec![],
+                termination,
+                output: vec![],
+            }
+        }
+
+        /// Prints the buffered output as it would have been echoed by an unbuffered run.
+        pub fn replay(&self, format: Option<OutputFormat>) {
+            for line in &self.output {
+                match line {
+                    Output::Stdout(line) => echo(line, parse_record(line).as_ref(), format),
+                    Output::Stderr(line) => eprintln!("{line}"),
+                }
+            }
+        }
+    }
+
+    /// A line printed by a child process.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Output {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    /// Run the solution of a given day, echoing its records in `format` (or nothing if `None`) as they arrive.
+    /// With `buffered`, the output is kept in [`SolutionRun::output`] instead, to be replayed later.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        warmup: Option<u32>,
+        format: Option<OutputFormat>,
+        limits: &Limits,
+        buffered: bool,
+    ) -> Result<SolutionRun, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if registry::find(year, day).is_none() {
+            return Ok(SolutionRun::empty(Termination::Exited));
+        }
+
+        let year = year.to_string();
+        let day_padded = day.to_string();
+        let warmup = warmup.map(|x| x.to_string());
+        let mut args = vec!["run", "--year", &year, &day_padded, "--format", "json"];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
+
+            if let Some(warmup) = &warmup {
+                args.push("--warmup");
+                args.push(warmup);
+            }
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr while collecting the records from stdout.
 
-        let mut cmd = Command::new("cargo")
+        let mut command = Command::new(env::current_exe()?);
+        command
             .args(&args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+            .stderr(Stdio::piped());
+
+        if let Some(bytes) = limits.memory {
+            limit_memory(&mut command, bytes);
+        }
+
+        // e.g. a memory limit too low to load the binary.
+        let mut cmd = match command.spawn() {
+            Ok(cmd) => cmd,
+            Err(e) => {
+                return Ok(SolutionRun::empty(Termination::Failed(format!(
+                    "could not start: {e}"
+                ))))
+            }
+        };
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        // kill the child once it exceeds its timeout, which also closes its stdout.
+        let cmd = Arc::new(Mutex::new(cmd));
+        let (done, finished) = mpsc::channel::<()>();
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        let watchdog = limits.timeout.map(|timeout| {
+            let cmd = Arc::clone(&cmd);
+            thread::spawn(move || match finished.recv_timeout(timeout) {
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let _ = cmd.lock().unwrap().kill();
+                    true
+                }
+                _ => false,
+            })
         });
 
+        let mut records = vec![];
+        let output = Arc::new(Mutex::new(vec![]));
+
+        let thread = {
+            let output = Arc::clone(&output);
+            thread::spawn(move || {
+                stderr.lines().for_each(|line| {
+                    let line = line.unwrap();
+                    if buffered {
+                        output.lock().unwrap().push(Output::Stderr(line));
+                    } else {
+                        eprintln!("{line}");
+                    }
+                });
+            })
+        };
+
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            let record = parse_record(&line);
+
+            if buffered {
+                output.lock().unwrap().push(Output::Stdout(line));
+            } else {
+                echo(&line, record.as_ref(), format);
+            }
+
+            records.extend(record);
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let _ = done.send(());
+
+        let timed_out = watchdog.is_some_and(|x| x.join().unwrap());
+        let status = cmd.lock().unwrap().wait()?;
+
+        let termination = match limits.timeout {
+            Some(timeout) if timed_out => Termination::TimedOut(timeout),
+            _ => Termination::from_status(status),
+        };
+
+        let output = std::mem::take(&mut *output.lock().unwrap());
+
+        Ok(SolutionRun {
+            records,
+            termination,
+            output,
+        })
+    }
+
+    /// Echoes a line of the child's stdout, `record` being the record it carries (if any).
+    fn echo(line: &str, record: Option<&Record>, format: Option<OutputFormat>) {
+        // children always log their progress, which only the JSON lines keep.
+        if record.is_none() && progress::parse_milestone(line).is_some() {
+            if format == Some(OutputFormat::Json) {
+                println!("{line}");
+            }
+            return;
+        }
 
-        Ok(output)
+        match (record, format) {
+            (Some(record), Some(OutputFormat::Text)) => print_record(record),
+            (Some(_), Some(OutputFormat::Json)) => println!("{line}"),
+            (Some(_), None) => {}
+            // anything else was printed by the solution itself, keep it out of the JSON lines.
+            (None, Some(OutputFormat::Text)) => println!("{line}"),
+            (None, _) => eprintln!("{line}"),
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
+    /// Limits the address space of the child process, allocations beyond it fail.
+    #[cfg(unix)]
+    fn limit_memory(command: &mut Command, bytes: u64) {
+        use std::os::unix::process::CommandExt;
+
+        let limit = libc::rlimit {
+            rlim_cur: bytes as libc::rlim_t,
+            rlim_max: bytes as libc::rlim_t,
+        };
+
+        // SAFETY: `setrlimit` is async-signal-safe and `limit` is moved into the closure.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(std::io::Error::last_os_error())
+                }
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn limit_memory(_: &mut Command, _: u64) {
+        eprintln!("Memory limits are only supported on unix.");
+    }
+
+    pub fn parse_record(line: &str) -> Option<Record> {
+        serde_json::from_str(line).ok()
+    }
+
+    /// The timings of a day for the README table, [`None`] if nothing was run. The parts of a day that did not
+    /// finish show why.
+    pub fn timings_from_run(run: &SolutionRun, year: Year, day: Day) -> Option<super::Timings> {
+        match run.termination {
+            // a skipped day was not measured, its row keeps the timings of earlier runs.
+            Termination::Skipped => None,
+            Termination::Exited if run.records.is_empty() => None,
+            Termination::Exited => Some(timings_from_records(&run.records, year, day)),
+            _ => {
+                let mut timings = timings_from_records(&run.records, year, day);
+                timings
+                    .part_1
+                    .get_or_insert_with(|| run.termination.label().into());
+                timings
+                    .part_2
+                    .get_or_insert_with(|| run.termination.label().into());
+                Some(timings)
+            }
+        }
+    }
+
+    /// Collects the timings of the benched parts of a day.
+    pub fn timings_from_records(records: &[Record], year: Year, day: Day) -> super::Timings {
         let mut timings = super::Timings {
+            year,
             day,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_memory: None,
+            part_2_memory: None,
             total_nanos: 0_f64,
+            last_measured: None,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+        for record in records {
+            // the time of a part that returned an error or panicked says nothing about the solution.
+            let label = match record.status {
+                Status::Failed => Some("error"),
+                Status::Panicked => Some("panicked"),
+                Status::Solved | Status::Unsolved => None,
+            };
+
+            if let Some(label) = label {
+                match record.part {
+                    1 => timings.part_1 = Some(label.into()),
+                    2 => timings.part_2 = Some(label.into()),
+                    _ => {}
                 }
+                continue;
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            let Some(stats) = &record.stats else {
+                continue;
+            };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            let timing_str = Some(format!("{:.1?}", record.duration));
+
+            match record.part {
+                1 => {
+                    timings.part_1 = timing_str;
+                    timings.part_1_stats = Some(stats.clone());
+                    timings.part_1_memory = record.memory;
+                }
+                2 => {
+                    timings.part_2 = timing_str;
+                    timings.part_2_stats = Some(stats.clone());
+                    timings.part_2_memory = record.memory;
                 }
+                _ => continue,
+            }
 
-                timings.total_nanos += nanos;
-            });
+            timings.total_nanos += record.duration.as_nanos() as f64;
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -202,53 +686,74 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_record, timings_from_records, Termination};
+        use crate::template::runner::Status;
 
-        use crate::day;
+        use crate::{day, year};
+
+        fn parse(lines: &[&str]) -> Vec<super::Record> {
+            lines.iter().filter_map(|l| parse_record(l)).collect()
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let records = parse(&[
+                r#"{"year":2023,"day":1,"part":1,"answer":"0","status":"solved","duration":74,"samples":100000,"stats":{"cold":80,"warmup":3,"samples":100000,"mean":75,"median":74,"min":70,"max":90,"p95":80,"stddev":2,"outliers":10}}"#,
+                r#"{"year":2023,"day":1,"part":2,"answer":"10","status":"solved","duration":74130000,"samples":10,"stats":{"cold":80000000,"warmup":3,"samples":10,"mean":74200000,"median":74130000,"min":74000000,"max":75000000,"p95":75000000,"stddev":10,"outliers":0}}"#,
+                "",
+            ]);
+            let res = timings_from_records(&records, year!(2023), day!(1));
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().outliers, 10);
+            assert_eq!(res.part_2_stats.unwrap().cold.as_millis(), 80);
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn test_patterns_in_output() {
+            let records = parse(&[
+                "Part 1: @ @ @ ( ) ms (2s @ 5 samples)",
+                r#"{"year":2023,"day":1,"part":1,"answer":"(2s @ 5 samples)\n{}","status":"solved","duration":100,"samples":1,"stats":null}"#,
+            ]);
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].answer.as_deref(), Some("(2s @ 5 samples)\n{}"));
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let records = parse(&[
+                r#"{"year":2023,"day":1,"part":1,"answer":null,"status":"unsolved","duration":5,"samples":1,"stats":null}"#,
+                r#"{"year":2023,"day":1,"part":2,"answer":null,"status":"unsolved","duration":5,"samples":1,"stats":null}"#,
+            ]);
+            assert_eq!(records[0].status, Status::Unsolved);
+            let res = timings_from_records(&records, year!(2023), day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_termination_from_status() {
+            let status = |script: &str| {
+                let status = std::process::Command::new("sh")
+                    .args(["-c", script])
+                    .status()
+                    .unwrap();
+                Termination::from_status(status)
+            };
+
+            assert_eq!(status("exit 0"), Termination::Exited);
+            assert_eq!(
+                status("exit 101"),
+                Termination::Failed("exited with status 101".into())
+            );
+            assert_eq!(
+                status("kill -9 $$"),
+                Termination::Failed("killed by signal 9".into())
+            );
+            assert_eq!(status("kill -9 $$").label(), "crashed");
+        }
     }
 }
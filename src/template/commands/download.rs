@@ -1,15 +1,31 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::{get_data_dir, get_data_path};
+use crate::{Day, Year};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(year: Year, day: Day) {
+    if let Err(e) = download(year, day) {
+        eprintln!("failed to download day {day}: {e}");
         process::exit(1);
     };
 }
+
+fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+
+    let input_path = get_data_path("inputs", year, day);
+    let puzzle_path = get_data_path("puzzles", year, day).with_extension("md");
+    fs::create_dir_all(get_data_dir("inputs", year))?;
+    fs::create_dir_all(get_data_dir("puzzles", year))?;
+
+    fs::write(&input_path, client.input(year, day)?)?;
+    fs::write(&puzzle_path, client.puzzle(year, day)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to {:?}.", input_path.display());
+    println!(
+        "🎄 Successfully wrote puzzle to {:?}.",
+        puzzle_path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::{fs, process};
+
+use crate::template::commands::scaffold;
+use crate::template::examples;
+use crate::template::get_bin_name;
+use crate::{Day, Year};
+
+/// Extracts the examples of the stored puzzle description to `data/<year>/examples` and prints their answers.
+/// Existing example files of the day are only replaced with `force`, together with the day's entries of the
+/// examples manifest.
+pub fn handle(year: Year, day: Day, force: bool, answer_type: &str) {
+    let examples = match examples::read(year, day) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("Failed to read puzzle description of day {day}: {e}. Run `cargo download {day}` first.");
+            process::exit(1);
+        }
+    };
+
+    if examples.inputs.is_empty() {
+        println!("No examples found in the puzzle description of day {day}.");
+        return;
+    }
+
+    // examples may have been edited by hand.
+    let existing = examples::files(year, day);
+
+    if !existing.is_empty() && !force {
+        eprintln!("Example files of day {day} exist already, pass --force to replace them:");
+
+        for path in existing {
+            eprintln!("{:?}", path.display());
+        }
+
+        process::exit(1);
+    }
+
+    match examples.write(year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote example to {:?}.", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to write example files: {e}");
+            process::exit(1);
+        }
+    }
+
+    // the entries of the manifest name the example files, which may have changed.
+    let module_path = format!("src/bin/{}.rs", get_bin_name(year, day));
+
+    if fs::read_to_string(module_path).is_ok_and(|x| x.contains("example_tests!")) {
+        let entries = scaffold::manifest_entries(year, day, &examples, answer_type);
+
+        match examples::replace_in_manifest(year, day, &entries) {
+            Ok(()) => println!(
+                "Replaced example tests of day {day} in {:?}.",
+                examples::manifest_path(year).display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to write examples manifest: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("---");
+
+    for expected in &examples.expected {
+        println!(
+            "Part {}: {} (example {:?})",
+            expected.part,
+            expected.answer,
+            examples.path(year, day, expected.example).display()
+        );
+    }
+}
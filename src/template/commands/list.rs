@@ -0,0 +1,15 @@
+use crate::template::registry;
+use crate::Year;
+
+pub fn handle(year: Year) {
+    let solutions = registry::solutions_of(year);
+
+    if solutions.is_empty() {
+        println!("No solutions registered for {year} yet.");
+        return;
+    }
+
+    for solution in solutions {
+        println!("Day {}", solution.day);
+    }
+}
@@ -1,5 +1,12 @@
 pub mod all;
 pub mod download;
+pub mod examples;
+pub mod list;
+pub mod profile;
 pub mod read;
+pub mod run;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod verify;
+pub mod watch;
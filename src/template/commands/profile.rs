@@ -0,0 +1,183 @@
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+use inferno::collapse::perf::{Folder, Options as FoldOptions};
+use inferno::collapse::Collapse;
+use inferno::flamegraph::{self, Options as FlamegraphOptions};
+
+use crate::template::get_bin_name;
+use crate::{Day, Year};
+
+/// Sampling frequency of `perf record`, off the round numbers to not run in lockstep with timers.
+const FREQUENCY: &str = "997";
+
+#[derive(Debug)]
+pub enum Error {
+    PerfNotFound,
+    Command(String),
+    Flamegraph(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::PerfNotFound => {
+                f.write_str("`perf` was not found, install it (e.g. `linux-tools`) to profile")
+            }
+            Error::Command(e) => f.write_str(e),
+            Error::Flamegraph(e) => write!(f, "could not render the flamegraph: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Samples the release build of a day with `perf` and renders a flamegraph to `etc/flamegraph.<year>-<day>.svg`.
+pub fn handle(year: Year, day: Day, part: Option<u8>) {
+    match profile(year, day, part) {
+        Ok(path) => println!("🔥 Successfully wrote flamegraph to {:?}.", path.display()),
+        Err(e) => {
+            eprintln!("failed to profile day {day}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn profile(year: Year, day: Day, part: Option<u8>) -> Result<PathBuf, Error> {
+    if Command::new("perf")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_err()
+    {
+        return Err(Error::PerfNotFound);
+    }
+
+    let bin = get_bin_name(year, day);
+
+    let executable = build(&bin)?;
+
+    fs::create_dir_all("etc")?;
+    let data = PathBuf::from(format!("etc/perf.{bin}.data"));
+    let flamegraph = PathBuf::from(format!("etc/flamegraph.{bin}.svg"));
+
+    let mut record = Command::new("perf");
+    record
+        .args(["record", "-F", FREQUENCY, "--call-graph", "dwarf", "-o"])
+        .arg(&data)
+        .arg("--")
+        .arg(executable)
+        .arg("--time");
+
+    if let Some(part) = part {
+        record.args(["--part", &part.to_string()]);
+    }
+
+    let result = run(&mut record).and_then(|()| render(year, day, &data, &flamegraph));
+    let _ = fs::remove_file(&data);
+
+    result.map(|()| flamegraph)
+}
+
+/// Builds the release binary `bin` and returns the path of its executable, which cargo reports as it may live in
+/// another target directory (`CARGO_TARGET_DIR` or `build.target-dir`).
+fn build(bin: &str) -> Result<PathBuf, Error> {
+    // debug info of release builds is enabled in `[profile.release]`, which resolves the symbols.
+    let output = Command::new("cargo")
+        .args(["build", "--release", "--bin", bin])
+        .arg("--message-format=json-render-diagnostics")
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::Command(format!("`cargo build` {}", output.status)));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| executable(line, bin))
+        .ok_or_else(|| {
+            Error::Command(format!(
+                "`cargo build` did not report the executable of {bin}"
+            ))
+        })
+}
+
+/// The executable of `bin` in a message of `cargo build --message-format=json`, if it is its artifact.
+fn executable(message: &str, bin: &str) -> Option<PathBuf> {
+    let message: serde_json::Value = serde_json::from_str(message).ok()?;
+
+    if message["reason"] != "compiler-artifact" || message["target"]["name"] != bin {
+        return None;
+    }
+
+    message["executable"].as_str().map(PathBuf::from)
+}
+
+/// Folds the stacks sampled into `data` and renders them as a flamegraph SVG.
+fn render(year: Year, day: Day, data: &Path, flamegraph: &Path) -> Result<(), Error> {
+    let script = Command::new("perf")
+        .arg("script")
+        .arg("-i")
+        .arg(data)
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    if !script.status.success() {
+        return Err(Error::Command(format!("`perf script` {}", script.status)));
+    }
+
+    let mut folded = vec![];
+    Folder::from(FoldOptions::default()).collapse(&script.stdout[..], &mut folded)?;
+
+    let mut options = FlamegraphOptions::default();
+    options.title = format!("Day {} of {year}", day.into_inner());
+
+    let writer = BufWriter::new(File::create(flamegraph)?);
+    flamegraph::from_reader(&mut options, BufReader::new(&folded[..]), writer)
+        .map_err(|e| Error::Flamegraph(e.to_string()))
+}
+
+fn run(command: &mut Command) -> Result<(), Error> {
+    let status = command
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Command(format!("{command:?} {status}")))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::executable;
+    use std::path::PathBuf;
+
+    #[test]
+    fn finds_executable_in_build_messages() {
+        let artifact = r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"2023-01"},"executable":"/tmp/target/release/2023-01","fresh":true}"#;
+
+        assert_eq!(
+            executable(artifact, "2023-01"),
+            Some(PathBuf::from("/tmp/target/release/2023-01"))
+        );
+        assert_eq!(executable(artifact, "2023-02"), None);
+        assert_eq!(
+            executable(r#"{"reason":"build-finished","success":true}"#, "2023-01"),
+            None
+        );
+    }
+}
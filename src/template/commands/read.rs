@@ -1,16 +1,24 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::{get_data_dir, get_data_path};
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(year: Year, day: Day) {
+    if let Err(e) = read(year, day) {
+        eprintln!("failed to read day {day}: {e}");
         process::exit(1);
     };
 }
+
+/// Fetches the latest puzzle description (it grows once part one is solved), stores it and prints it.
+fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let puzzle = AocClient::from_env()?.puzzle(year, day)?;
+    fs::create_dir_all(get_data_dir("puzzles", year))?;
+    fs::write(
+        get_data_path("puzzles", year, day).with_extension("md"),
+        &puzzle,
+    )?;
+    println!("{puzzle}");
+    Ok(())
+}
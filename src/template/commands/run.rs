@@ -0,0 +1,51 @@
+use std::fs;
+
+use crate::template::runner::{self, OutputFormat};
+use crate::template::{get_data_path, registry, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, Year};
+
+/// Runs the given days of a year (or every registered day of it if none is given) in the current process.
+pub fn handle(year: Year, days: &[Day], format: OutputFormat) {
+    let solutions = if days.is_empty() {
+        registry::solutions_of(year)
+    } else {
+        days.iter()
+            .filter_map(|&day| registry::find(year, day))
+            .collect()
+    };
+
+    let mut records = vec![];
+
+    for (i, solution) in solutions.iter().enumerate() {
+        let day = solution.day;
+
+        if format == OutputFormat::Text {
+            if i > 0 {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+        }
+
+        // `--input` and `--example` are read by the runner, a missing puzzle input only skips its day.
+        let input = if runner::is_custom_input() {
+            Ok(runner::read_input(year, day))
+        } else {
+            fs::read_to_string(get_data_path("inputs", year, day))
+        };
+
+        match input {
+            Ok(input) => records.extend(solution.run(&input)),
+            Err(e) => eprintln!("Could not read input of day {day}: {e}"),
+        }
+    }
+
+    for day in days {
+        if registry::find(year, *day).is_none() {
+            eprintln!("Day {day} is not solved yet.");
+        }
+    }
+
+    runner::exit_if_panicked(&records);
+}
@@ -1,51 +1,258 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::examples::{self, Examples};
+use crate::template::{get_bin_name, get_data_dir, get_data_path};
+use crate::{Day, Year};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// Directory of the user-defined templates, `<name>.rs.tmpl` is picked with `--template <name>`.
+static TEMPLATE_DIR: &str = ".aoc/templates";
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// Used when no `--template` is given and there is no `default.rs.tmpl`.
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+advent_of_code::example_tests!();
+"#;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+/// Loads the template `name` (or the default one) from [`TEMPLATE_DIR`].
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let path = Path::new(TEMPLATE_DIR).join(format!("{}.rs.tmpl", name.unwrap_or("default")));
+
+    match (fs::read_to_string(&path), name) {
+        (Ok(template), _) => Ok(template),
+        (Err(e), None) if e.kind() == io::ErrorKind::NotFound => Ok(MODULE_TEMPLATE.to_string()),
+        (Err(e), _) => Err(format!(
+            "could not read template {:?}: {e}. Available templates: {}.",
+            path.display(),
+            template_names().join(", ")
+        )),
     }
+}
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+/// Names of the templates in [`TEMPLATE_DIR`].
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_suffix(".rs.tmpl").map(str::to_string)
+        })
+        .collect();
+
+    names.sort();
+    names
+}
+
+/// Values of the placeholders of a template. Longer names come first, as some names are prefixes of others.
+fn placeholders(
+    year: Year,
+    day: Day,
+    answer_type: &str,
+    examples: &Examples,
+) -> Vec<(&'static str, String)> {
+    let (part_one_file, part_one_example, part_one_expected) =
+        part_test(year, day, examples, 1, answer_type);
+    let (part_two_file, part_two_example, part_two_expected) =
+        part_test(year, day, examples, 2, answer_type);
+
+    vec![
+        (
+            "EXAMPLE_TESTS",
+            example_tests(year, day, examples, answer_type),
+        ),
+        ("PART_ONE_EXAMPLE_FILE", part_one_file),
+        ("PART_TWO_EXAMPLE_FILE", part_two_file),
+        ("PART_ONE_EXAMPLE", part_one_example),
+        ("PART_TWO_EXAMPLE", part_two_example),
+        ("PART_ONE_EXPECTED", part_one_expected),
+        ("PART_TWO_EXPECTED", part_two_expected),
+        ("ANSWER_TYPE", answer_type.to_string()),
+        ("YEAR_NUMBER", year.to_string()),
+        ("DAY_NUMBER", day.into_inner().to_string()),
+        ("DAY_PADDED", day.to_string()),
+    ]
+}
+
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |acc, (name, value)| {
+            acc.replace(name, value)
+        })
+}
+
+/// The first example case of a part: the path of its example file, the expression reading it and the expected
+/// result.
+fn part_test(
+    year: Year,
+    day: Day,
+    examples: &Examples,
+    part: u8,
+    answer_type: &str,
+) -> (String, String, String) {
+    let case = example_cases(year, day, examples, answer_type)
+        .into_iter()
+        .find(|x| x.part == part)
+        .unwrap();
+
+    (
+        format!("data/{year}/examples/{}", case.file),
+        format!(
+            "advent_of_code::template::read_example(YEAR, {:?})",
+            case.file
+        ),
+        case.expected,
+    )
+}
+
+/// An example a part is tested with: the part, the name of the example file and the expected result.
+struct ExampleCase {
+    part: u8,
+    file: String,
+    expected: String,
+}
+
+/// One case per expected answer, each with its own example file. A part without a known answer is tested with
+/// the first example and expects `None`.
+fn example_cases(year: Year, day: Day, examples: &Examples, answer_type: &str) -> Vec<ExampleCase> {
+    let file = |example| {
+        let path = examples.path(year, day, example);
+        path.file_name().unwrap().to_string_lossy().into_owned()
+    };
+
+    let mut cases: Vec<ExampleCase> = examples
+        .expected
+        .iter()
+        .map(|x| ExampleCase {
+            part: x.part,
+            file: file(x.example),
+            expected: format_expected(Some(&x.answer), answer_type),
+        })
+        .collect();
+
+    for part in [1, 2] {
+        if examples.expected(part).is_none() {
+            cases.push(ExampleCase {
+                part,
+                file: file(0),
+                expected: format_expected(None, answer_type),
+            });
+        }
+    }
+
+    cases.sort_by_key(|x| x.part);
+    cases
+}
+
+/// A test module with one test per example case, for templates with hand-written tests.
+fn example_tests(year: Year, day: Day, examples: &Examples, answer_type: &str) -> String {
+    let mut names: Vec<String> = vec![];
+    let mut tests = vec![];
+
+    for case in example_cases(year, day, examples, answer_type) {
+        let function = if case.part == 1 {
+            "part_one"
+        } else {
+            "part_two"
+        };
+        let stem = case.file.trim_end_matches(".txt").replace('-', "_");
+        let mut name = format!("test_{function}_{stem}");
+
+        if names.contains(&name) {
+            name = format!("{name}_{}", names.len() + 1);
+        }
+
+        tests.push(format!(
+            "    #[test]\n    fn {name}() {{\n        let input = advent_of_code::template::read_example(YEAR, {:?});\n        assert_eq!({function}(&input), {});\n    }}\n",
+            case.file, case.expected
+        ));
+        names.push(name);
+    }
+
+    format!(
+        "#[cfg(test)]\nmod tests {{\n    use super::*;\n\n{}}}",
+        tests.join("\n")
+    )
+}
+
+/// Entries of the examples manifest, one per example case, e.g. `08 1 08-1.txt = Some(2)` (see `example_tests!`).
+pub(crate) fn manifest_entries(
+    year: Year,
+    day: Day,
+    examples: &Examples,
+    answer_type: &str,
+) -> Vec<String> {
+    example_cases(year, day, examples, answer_type)
+        .into_iter()
+        .map(|x| format!("{day} {} {} = {}", x.part, x.file, x.expected))
+        .collect()
+}
+
+/// Formats an expected answer as a value of `Option<answer_type>`, unknown answers become `None`.
+fn format_expected(answer: Option<&str>, answer_type: &str) -> String {
+    let Some(answer) = answer else {
+        return "None".to_string();
+    };
+
+    let is_integer = answer
+        .trim_start_matches('-')
+        .bytes()
+        .all(|x| x.is_ascii_digit());
+
+    match answer_type {
+        "String" => format!("Some({answer:?}.to_string())"),
+        "&str" | "&'static str" => format!("Some({answer:?})"),
+        _ if is_integer && !answer.is_empty() => format!("Some({answer})"),
+        _ => "None".to_string(),
     }
 }
-"#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: impl AsRef<Path>) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: impl AsRef<Path>) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, template: Option<&str>, answer_type: &str) {
+    let input_path = get_data_path("inputs", year, day);
+    let module_path = format!("src/bin/{}.rs", get_bin_name(year, day));
+
+    let template = match load_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    for folder in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(get_data_dir(folder, year)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
+
+    // use the examples of the puzzle description if it was downloaded already.
+    let examples = examples::read(year, day).unwrap_or_default();
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -55,11 +262,9 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let module = render(&template, &placeholders(year, day, answer_type, &examples));
+
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -69,26 +274,202 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // modules of templates with hand-written tests do not read the manifest.
+    if module.contains("example_tests!") {
+        let entries = manifest_entries(year, day, &examples, answer_type);
+
+        match examples::append_to_manifest(year, day, &entries) {
+            Ok(true) => println!(
+                "Added example tests to {:?}",
+                examples::manifest_path(year).display()
+            ),
+            Ok(false) => println!("Kept existing example tests of day {day}"),
+            Err(e) => {
+                eprintln!("Failed to write examples manifest: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    // keep an input that was downloaded before scaffolding.
+    if input_path.exists() {
+        println!("Kept existing input file {:?}", input_path.display());
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file {:?}", input_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    // keep examples that exist already, they may have been edited by hand (see the `examples` command).
+    if !examples::files(year, day).is_empty() {
+        println!("Kept existing example files of day {day}");
+    } else if examples.inputs.is_empty() {
+        let example_path = get_data_path("examples", year, day);
+
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file {:?}", example_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    } else {
+        match examples.write(year, day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Created example file {:?}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to write example files: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        example_tests, format_expected, manifest_entries, placeholders, render, MODULE_TEMPLATE,
+    };
+    use crate::template::examples::{Examples, Expected};
+    use crate::{day, year};
+
+    #[test]
+    fn formats_expected() {
+        assert_eq!(format_expected(Some("142"), "u32"), "Some(142)");
+        assert_eq!(format_expected(Some("-3"), "i64"), "Some(-3)");
+        assert_eq!(format_expected(Some("ABC"), "u32"), "None");
+        assert_eq!(
+            format_expected(Some("ABC"), "String"),
+            "Some(\"ABC\".to_string())"
+        );
+        assert_eq!(format_expected(None, "u64"), "None");
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let examples = Examples {
+            inputs: vec!["a\n".into(), "b\n".into()],
+            expected: vec![Expected {
+                part: 2,
+                example: 1,
+                answer: "6".into(),
+            }],
+        };
+
+        let placeholders = placeholders(year!(2023), day!(8), "u64", &examples);
+        let module = render(MODULE_TEMPLATE, &placeholders);
+        assert!(module.starts_with("advent_of_code::solution!(2023, 8);"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<u64> {"));
+        assert!(module.contains("advent_of_code::example_tests!();"));
+
+        let test = render(
+            "part_one(&PART_ONE_EXAMPLE); assert_eq!(result, PART_TWO_EXPECTED);",
+            &placeholders,
+        );
+        assert_eq!(
+            test,
+            "part_one(&advent_of_code::template::read_example(YEAR, \"08-1.txt\")); assert_eq!(result, Some(6));"
+        );
+        assert_eq!(
+            render("PART_TWO_EXAMPLE_FILE DAY_PADDED", &placeholders),
+            "data/2023/examples/08-2.txt 08"
+        );
+    }
+
+    #[test]
+    fn renders_example_tests() {
+        let examples = Examples {
+            inputs: vec!["a\n".into(), "b\n".into(), "c\n".into()],
+            expected: vec![
+                Expected {
+                    part: 1,
+                    example: 0,
+                    answer: "2".into(),
+                },
+                Expected {
+                    part: 1,
+                    example: 1,
+                    answer: "6".into(),
+                },
+                Expected {
+                    part: 2,
+                    example: 2,
+                    answer: "6".into(),
+                },
+            ],
+        };
+
+        let tests = example_tests(year!(2023), day!(8), &examples, "u64");
+        assert!(tests.starts_with("#[cfg(test)]\nmod tests {\n    use super::*;\n"));
+        assert!(tests.contains("fn test_part_one_08_1() {\n        let input = advent_of_code::template::read_example(YEAR, \"08-1.txt\");\n        assert_eq!(part_one(&input), Some(2));"));
+        assert!(tests.contains("fn test_part_one_08_2() {\n        let input = advent_of_code::template::read_example(YEAR, \"08-2.txt\");\n        assert_eq!(part_one(&input), Some(6));"));
+        assert!(tests.contains("fn test_part_two_08_3() {\n        let input = advent_of_code::template::read_example(YEAR, \"08-3.txt\");\n        assert_eq!(part_two(&input), Some(6));"));
+
+        let tests = example_tests(year!(2023), day!(1), &Examples::default(), "u32");
+        assert!(tests.contains(
+            "read_example(YEAR, \"01.txt\");\n        assert_eq!(part_one(&input), None);"
+        ));
+        assert!(tests.contains("fn test_part_two_01() {"));
+    }
+
+    #[test]
+    fn lists_manifest_entries() {
+        let examples = Examples {
+            inputs: vec!["a\n".into(), "b\n".into(), "c\n".into()],
+            expected: vec![
+                Expected {
+                    part: 1,
+                    example: 0,
+                    answer: "2".into(),
+                },
+                Expected {
+                    part: 1,
+                    example: 1,
+                    answer: "6".into(),
+                },
+                Expected {
+                    part: 2,
+                    example: 2,
+                    answer: "6".into(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            manifest_entries(year!(2023), day!(8), &examples, "u64"),
+            [
+                "08 1 08-1.txt = Some(2)",
+                "08 1 08-2.txt = Some(6)",
+                "08 2 08-3.txt = Some(6)"
+            ]
+        );
+
+        let examples = Examples {
+            inputs: vec!["a\n".into(), "b\n".into()],
+            expected: vec![Expected {
+                part: 2,
+                example: 1,
+                answer: "6".into(),
+            }],
+        };
+
+        assert_eq!(
+            manifest_entries(year!(2023), day!(8), &examples, "u64"),
+            ["08 1 08-1.txt = None", "08 2 08-2.txt = Some(6)"]
+        );
+    }
 }
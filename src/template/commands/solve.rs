@@ -1,25 +1,79 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::Day;
+use crate::template::runner::OutputFormat;
+use crate::template::{allocations, get_bin_name};
+use crate::{Day, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    time: bool,
+    warmup: Option<u32>,
+    submit_part: Option<u8>,
+    force: bool,
+    format: OutputFormat,
+    input: Option<String>,
+    example: Option<u8>,
+    part: Option<u8>,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    // build the day with the counting allocator when this binary has it.
+    if allocations::ENABLED {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_stats".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if force {
+            cmd_args.push("--force".to_string());
+        }
     }
 
     if time {
         cmd_args.push("--time".to_string());
     }
 
+    if let Some(warmup) = warmup {
+        cmd_args.push("--warmup".to_string());
+        cmd_args.push(warmup.to_string());
+    }
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    if let Some(example) = example {
+        cmd_args.push("--example".to_string());
+        cmd_args.push(example.to_string());
+    }
+
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -27,5 +81,10 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    // keep the exit code of the day, e.g. 101 when one of its parts panicked.
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
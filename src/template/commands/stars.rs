@@ -0,0 +1,17 @@
+use std::process;
+
+use crate::template::readme_stars;
+use crate::Year;
+
+/// Rebuilds the ⭐️ table of the readme from the confirmed answers in `data/<year>/answers`, switching it to
+/// `year` if it shows another one.
+pub fn handle(year: Year) {
+    match readme_stars::update(year, &[], true) {
+        Ok(true) => println!("⭐ Successfully updated README stars."),
+        Ok(false) => println!("README stars are up to date."),
+        Err(e) => {
+            eprintln!("failed to update README stars: {e:?}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::process;
+
+use crate::template::answers::Verdict;
+use crate::template::commands::all::{child_commands, Limits};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Year};
+
+/// Runs every day of a year and compares the answers with the confirmed ones in `data/<year>/answers`.
+pub fn handle(year: Year) {
+    let (mut matched, mut mismatched, mut missing) = (0, 0, 0);
+
+    for day in all_days() {
+        let records =
+            child_commands::run_solution(year, day, false, None, None, &Limits::default(), false)
+                .unwrap()
+                .records;
+
+        if records.is_empty() {
+            continue;
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        for record in records {
+            let answer = record.answer.as_deref().unwrap_or("✖");
+            let part = record.part;
+
+            match record.verdict {
+                Verdict::Match => {
+                    matched += 1;
+                    println!("Part {part}: ✔ {answer}");
+                }
+                Verdict::Mismatch { expected } => {
+                    mismatched += 1;
+                    println!("Part {part}: ✘ {answer} (expected {expected})");
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!("Part {part}: ? {answer} (no known answer)");
+                }
+            }
+        }
+    }
+
+    println!("---");
+    println!("{matched} matched, {mismatched} mismatched, {missing} missing.");
+
+    if mismatched > 0 {
+        process::exit(1);
+    }
+}
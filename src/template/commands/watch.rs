@@ -0,0 +1,92 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::examples;
+use crate::template::{get_bin_name, get_data_path, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Clears the screen and moves the cursor to the top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Re-runs the example tests and the solution of a day whenever its source, input or examples change.
+pub fn handle(year: Year, day: Day, release: bool) {
+    let bin = get_bin_name(year, day);
+    let mut last = None;
+
+    loop {
+        let snapshot = snapshot(&watched_paths(year, day));
+
+        if last.as_ref() != Some(&snapshot) {
+            last = Some(snapshot);
+            print!("{CLEAR_SCREEN}");
+            println!("{ANSI_BOLD}Watching day {day} of {year}{ANSI_RESET} (Ctrl-C to stop)");
+            println!("---");
+            run(&bin, release);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The source of the day, its input, all of its examples (`DD.txt` and `DD-N.txt`) and the manifest of the example
+/// tests.
+fn watched_paths(year: Year, day: Day) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{}.rs", get_bin_name(year, day))),
+        get_data_path("inputs", year, day),
+        examples::manifest_path(year),
+    ];
+
+    paths.extend(examples::files(year, day));
+    paths
+}
+
+/// Modification times of `paths`, `None` for missing files.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|x| x.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Runs the example tests, then the solution on the real input (which prints answers and timings).
+fn run(bin: &str, release: bool) {
+    let mut test_args = vec!["test", "--quiet", "--bin", bin];
+    let mut run_args = vec!["run", "--quiet", "--bin", bin];
+
+    if release {
+        test_args.push("--release");
+        run_args.push("--release");
+    }
+
+    println!("{ANSI_BOLD}Example tests{ANSI_RESET}");
+
+    // still run the solution when an example fails, its answer might be right anyway.
+    cargo(&test_args);
+
+    println!();
+    println!("{ANSI_BOLD}Solution{ANSI_RESET}");
+    cargo(&run_args);
+}
+
+fn cargo(args: &[&str]) {
+    let status = Command::new("cargo")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    if let Err(e) = status {
+        eprintln!("Failed to run cargo: {e}");
+    }
+}
@@ -0,0 +1,435 @@
+/// Module that extracts the examples of a puzzle and their expected answers from its markdown description
+/// (see [`puzzle_to_markdown`](crate::template::aoc_client::puzzle_to_markdown)).
+///
+/// A code block counts as an example when the paragraph before it mentions an example. The expected answer of
+/// an example is the last emphasized code (`` `*42*` ``) before the next example or the end of the part.
+/// When part two does not introduce an example of its own, its answer belongs to the first example of part one.
+use std::{fs, io, path::PathBuf};
+
+use crate::template::{get_data_dir, get_data_path};
+use crate::{Day, Year};
+
+static PART_TWO: &str = "## --- Part Two ---";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part: u8,
+    /// Index into [`Examples::inputs`].
+    pub example: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Examples {
+    pub inputs: Vec<String>,
+    pub expected: Vec<Expected>,
+}
+
+impl Examples {
+    /// Extracts the examples of a puzzle description.
+    #[must_use]
+    pub fn parse(markdown: &str) -> Self {
+        let mut examples = Self::default();
+
+        let (part_one, part_two) = match markdown.find(PART_TWO) {
+            Some(i) => (&markdown[..i], Some(&markdown[i..])),
+            None => (markdown, None),
+        };
+
+        examples.parse_part(1, part_one);
+
+        // fall back to the first code block for puzzles that do not announce their example.
+        if examples.inputs.is_empty() {
+            if let Some((_, input)) = code_blocks(part_one).first() {
+                examples.inputs.push(input.clone());
+            }
+        }
+
+        if let Some(part_two) = part_two {
+            examples.parse_part(2, part_two);
+        }
+
+        examples
+    }
+
+    fn parse_part(&mut self, part: u8, markdown: &str) {
+        let mut current = None;
+        let mut text = String::new();
+
+        for (before, input) in code_blocks(markdown) {
+            if !looks_like_example(&before) {
+                text.push_str(&before);
+                continue;
+            }
+
+            // the paragraph announcing an example belongs to it, e.g. "here is a situation that takes `*6*` steps:".
+            let (previous, intro) = before
+                .trim_end()
+                .rsplit_once("\n\n")
+                .unwrap_or(("", &before));
+            text.push_str(previous);
+            self.push_answer(part, current, &text);
+            text = intro.to_string();
+
+            current = Some(match self.inputs.iter().position(|x| *x == input) {
+                Some(i) => i,
+                None => {
+                    self.inputs.push(input);
+                    self.inputs.len() - 1
+                }
+            });
+        }
+
+        text.push_str(trailing_text(markdown));
+        self.push_answer(part, current, &text);
+    }
+
+    fn push_answer(&mut self, part: u8, example: Option<usize>, text: &str) {
+        let example = match example {
+            Some(x) => x,
+            None if part == 2 && !self.inputs.is_empty() => 0,
+            None => return,
+        };
+
+        if let Some(answer) = emphasized_code(text).pop() {
+            self.expected.push(Expected {
+                part,
+                example,
+                answer,
+            });
+        }
+    }
+
+    /// The first expected answer of a part.
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<&Expected> {
+        self.expected.iter().find(|x| x.part == part)
+    }
+
+    /// Path of an example, examples of days with several ones are numbered (`DD-N.txt`). Days without examples
+    /// get the empty `DD.txt` created by scaffold.
+    #[must_use]
+    pub fn path(&self, year: Year, day: Day, example: usize) -> PathBuf {
+        if self.inputs.len() <= 1 {
+            get_data_path("examples", year, day)
+        } else {
+            get_data_dir("examples", year).join(format!("{day}-{}.txt", example + 1))
+        }
+    }
+
+    /// Writes every example to `data/<year>/examples`, returns the written paths. The other example files of the
+    /// day are removed, e.g. `DD.txt` when there are several examples now.
+    pub fn write(&self, year: Year, day: Day) -> Result<Vec<PathBuf>, io::Error> {
+        fs::create_dir_all(get_data_dir("examples", year))?;
+
+        for path in files(year, day) {
+            fs::remove_file(path)?;
+        }
+
+        self.inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let path = self.path(year, day, i);
+                fs::write(&path, input)?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+/// The example files of a day, `DD.txt` and `DD-N.txt`.
+#[must_use]
+pub fn files(year: Year, day: Day) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(get_data_dir("examples", year))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let rest = name.strip_prefix(&day.to_string())?;
+            let is_numbered = rest
+                .strip_prefix('-')
+                .and_then(|x| x.strip_suffix(".txt"))
+                .is_some_and(|x| !x.is_empty() && x.bytes().all(|x| x.is_ascii_digit()));
+            (rest == ".txt" || is_numbered).then_some(path)
+        })
+        .collect();
+
+    files.sort();
+    files
+}
+
+/// Path of the manifest of the example tests of a year, see `example_tests!`.
+#[must_use]
+pub fn manifest_path(year: Year) -> PathBuf {
+    get_data_dir("examples", year).join("manifest")
+}
+
+/// Appends `entries` to the manifest of the example tests, unless it lists tests of `day` already. Returns whether
+/// the entries were added.
+pub fn append_to_manifest(year: Year, day: Day, entries: &[String]) -> Result<bool, io::Error> {
+    let path = manifest_path(year);
+    let mut manifest = match fs::read_to_string(&path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => MANIFEST_HEADER.to_string(),
+        Err(e) => return Err(e),
+    };
+
+    if manifest.lines().any(|line| is_entry_of(line, day)) {
+        return Ok(false);
+    }
+
+    push_entries(&mut manifest, entries);
+    fs::write(path, manifest)?;
+    Ok(true)
+}
+
+/// Replaces the entries of `day` in the manifest of the example tests with `entries`, in place of the first old
+/// one or else at the end.
+pub fn replace_in_manifest(year: Year, day: Day, entries: &[String]) -> Result<(), io::Error> {
+    let path = manifest_path(year);
+    let manifest = match fs::read_to_string(&path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => MANIFEST_HEADER.to_string(),
+        Err(e) => return Err(e),
+    };
+
+    fs::write(path, replace_entries(&manifest, day, entries))
+}
+
+fn replace_entries(manifest: &str, day: Day, entries: &[String]) -> String {
+    let mut replaced = String::new();
+    let mut is_replaced = false;
+
+    for line in manifest.lines() {
+        if !is_entry_of(line, day) {
+            replaced.push_str(line);
+            replaced.push('\n');
+        } else if !is_replaced {
+            push_entries(&mut replaced, entries);
+            is_replaced = true;
+        }
+    }
+
+    if !is_replaced {
+        push_entries(&mut replaced, entries);
+    }
+
+    // the blank line around a day without entries now.
+    while replaced.contains("\n\n\n") {
+        replaced = replaced.replace("\n\n\n", "\n\n");
+    }
+
+    replaced
+}
+
+/// Whether a line of the manifest is an entry of `day`.
+fn is_entry_of(line: &str, day: Day) -> bool {
+    line.split_whitespace()
+        .next()
+        .and_then(|x| x.parse::<u8>().ok())
+        .is_some_and(|x| x == day.into_inner())
+}
+
+/// Appends `entries` to a manifest, separated from the previous ones by a blank line.
+fn push_entries(manifest: &mut String, entries: &[String]) {
+    if !manifest.is_empty() && !manifest.ends_with("\n\n") {
+        manifest.push_str(if manifest.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        });
+    }
+
+    for entry in entries {
+        manifest.push_str(entry);
+        manifest.push('\n');
+    }
+}
+
+static MANIFEST_HEADER: &str = "\
+# Tests generated by `advent_of_code::example_tests!()`, one per line as
+# `<day> <part> <example file> [<solver>(<parameters>)] = <expected>`.
+# Without a solver, the part is called with the example. A solver is called with the example followed by its parameters.
+";
+
+/// Reads the stored puzzle description of a day (see the `download` and `read` commands) and extracts its examples.
+pub fn read(year: Year, day: Day) -> Result<Examples, io::Error> {
+    let path = get_data_path("puzzles", year, day).with_extension("md");
+    Ok(Examples::parse(&fs::read_to_string(path)?))
+}
+
+/// The fenced code blocks of `markdown`, each with the text preceding it.
+fn code_blocks(markdown: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("```\n") {
+        let Some(end) = rest[start + 4..].find("\n```") else {
+            break;
+        };
+
+        let before = rest[..start].to_string();
+        let input = rest[start + 4..start + 4 + end + 1].to_string();
+        blocks.push((before, input));
+        rest = &rest[start + 4 + end + 4..];
+    }
+
+    blocks
+}
+
+/// The text after the last code block of `markdown`.
+fn trailing_text(markdown: &str) -> &str {
+    match markdown.rfind("\n```") {
+        Some(i) if markdown[..i].contains("```\n") => &markdown[i + 4..],
+        _ => markdown,
+    }
+}
+
+/// Whether the paragraph before a code block announces an example.
+fn looks_like_example(before: &str) -> bool {
+    before
+        .trim_end()
+        .rsplit("\n\n")
+        .next()
+        .is_some_and(|x| x.to_ascii_lowercase().contains("example"))
+}
+
+/// The contents of emphasized code spans, i.e. `` `*42*` `` or `` *`42`* ``.
+fn emphasized_code(text: &str) -> Vec<String> {
+    let mut answers = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find("`*").into_iter().chain(rest.find("*`")).min() {
+        let delimiter = &rest[start..start + 2];
+        let closing = if delimiter == "`*" { "*`" } else { "`*" };
+        let body = &rest[start + 2..];
+
+        match body.find(closing) {
+            Some(end) if !body[..end].contains(['\n', '`', '*']) => {
+                answers.push(body[..end].to_string());
+                rest = &body[end + 2..];
+            }
+            _ => rest = body,
+        }
+    }
+
+    answers
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_code, replace_entries, Examples, Expected};
+    use crate::day;
+
+    static PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+The calibration document is `*wrong*`:
+
+```
+not an example
+```
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the values are `12` and `38`. Adding these together produces `*142*`.
+
+## --- Part Two ---
+
+Some digits are spelled out. For example:
+
+```
+two1nine
+eightwothree
+```
+
+In this example, the calibration values are `*29*` and `*83*`. Adding these together produces `*112*`.
+";
+
+    #[test]
+    fn extracts_examples() {
+        let examples = Examples::parse(PUZZLE);
+        assert_eq!(
+            examples.inputs,
+            vec!["1abc2\npqr3stu8vwx\n", "two1nine\neightwothree\n"]
+        );
+        assert_eq!(
+            examples.expected,
+            vec![
+                Expected {
+                    part: 1,
+                    example: 0,
+                    answer: "142".into()
+                },
+                Expected {
+                    part: 2,
+                    example: 1,
+                    answer: "112".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_example_of_part_one() {
+        let puzzle = "For example:\n\n```\n1\n2\n```\n\nSo `*3*`.\n\n## --- Part Two ---\n\nNow it is `*2*`.\n";
+        let examples = Examples::parse(puzzle);
+        assert_eq!(examples.inputs, vec!["1\n2\n"]);
+        assert_eq!(examples.expected(2).unwrap().example, 0);
+        assert_eq!(examples.expected(2).unwrap().answer, "2");
+    }
+
+    #[test]
+    fn finds_multiple_examples() {
+        let puzzle = "Here is an example:\n\n```\nA\n```\n\nIt takes `*2*` steps.\n\nAnother example:\n\n```\nB\n```\n\nIt takes `*6*` steps.\n";
+        let examples = Examples::parse(puzzle);
+        assert_eq!(examples.inputs, vec!["A\n", "B\n"]);
+        assert_eq!(examples.expected[1].example, 1);
+        assert_eq!(examples.expected[1].answer, "6");
+    }
+
+    #[test]
+    fn attributes_answer_of_announcement() {
+        let puzzle = "For example:\n\n```\nA\n```\n\nIt takes `*2*` steps.\n\nFor example, this takes `*6*` steps:\n\n```\nB\n```\n\nHow many steps?\n";
+        let examples = Examples::parse(puzzle);
+        assert_eq!(examples.expected[0].answer, "2");
+        assert_eq!(examples.expected[1].example, 1);
+        assert_eq!(examples.expected[1].answer, "6");
+    }
+
+    #[test]
+    fn replaces_entries_of_a_day() {
+        let manifest = "# header\n\n01 1 01.txt = Some(1)\n\n08 1 08.txt = None\n08 2 08.txt = None\n\n10 1 10.txt = None\n";
+        let entries = [
+            "08 1 08-1.txt = Some(2)".to_string(),
+            "08 2 08-2.txt = Some(6)".to_string(),
+        ];
+
+        assert_eq!(
+            replace_entries(manifest, day!(8), &entries),
+            "# header\n\n01 1 01.txt = Some(1)\n\n08 1 08-1.txt = Some(2)\n08 2 08-2.txt = Some(6)\n\n10 1 10.txt = None\n"
+        );
+        assert_eq!(
+            replace_entries(manifest, day!(8), &[]),
+            "# header\n\n01 1 01.txt = Some(1)\n\n10 1 10.txt = None\n"
+        );
+        assert_eq!(
+            replace_entries("# header\n", day!(2), &entries[..1]),
+            "# header\n\n08 1 08-1.txt = Some(2)\n"
+        );
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("a `*1*`, *`2`*, *em* `code` and `*3*`."),
+            vec!["1", "2", "3"]
+        );
+    }
+}
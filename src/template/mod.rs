@@ -1,48 +1,121 @@
-use crate::Day;
+use crate::{Day, Year};
+use std::path::PathBuf;
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod allocations;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod examples;
+pub mod progress;
 pub mod readme_benchmarks;
+pub mod readme_stars;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Directory of the data of a year, i.e. `data/<year>/<folder>`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn get_data_dir(folder: &str, year: Year) -> PathBuf {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+    cwd.join("data").join(year.to_string()).join(folder)
+}
+
+/// Path of the text file of a day in `data/<year>/<folder>`.
+#[must_use]
+pub fn get_data_path(folder: &str, year: Year, day: Day) -> PathBuf {
+    get_data_dir(folder, year).join(format!("{day}.txt"))
+}
+
+/// Name of the binary of a day, e.g. `2023-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// Helper function that reads a text file to a string.
+#[must_use]
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    let f = fs::read_to_string(get_data_path(folder, year, day));
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    let filepath = get_data_dir(folder, year).join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Reads the example `name` of `data/<year>/examples`, e.g. `08-1.txt`.
+#[must_use]
+pub fn read_example(year: Year, name: &str) -> String {
+    let f = fs::read_to_string(get_data_dir("examples", year).join(name));
+    f.expect("could not open example file")
+}
+
+/// Creates the constants `YEAR` and `DAY`, registers both parts and sets up the input and runner for each part.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr) => {
+        /// The year of the puzzle.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        advent_of_code::template::registry::submit! {
+            advent_of_code::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                part_one: |input| advent_of_code::template::runner::run_part(part_one, input, YEAR, DAY, 1),
+                part_two: |input| advent_of_code::template::runner::run_part(part_two, input, YEAR, DAY, 2),
+            }
+        }
+
+        // only the entry point of the day binary, unused when the day is linked into the main binary.
+        #[allow(dead_code)]
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input = read_input(YEAR, DAY);
+            let mut records = vec![];
+
+            if is_part_selected(1) {
+                records.push(run_part(part_one, &input, YEAR, DAY, 1));
+            }
+
+            if is_part_selected(2) {
+                records.push(run_part(part_two, &input, YEAR, DAY, 2));
+            }
+
+            exit_if_panicked(&records);
+        }
+    };
+}
+
+/// Generates one test per entry of the day in `data/<year>/examples/manifest`, e.g. `01 1 01-1.txt = Some(142)` or
+/// `11 2 11.txt solve(10) = 1030` for a solver taking parameters after the input. The tests are written by `build.rs`.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/example_tests/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
         }
     };
 }
@@ -0,0 +1,289 @@
+/// Module that reports the progress of long-running solution parts, see [`Progress`].
+///
+/// Progress is only shown during the first run of a part started by [`runner::run_part`](super::runner::run_part):
+/// as a bar on stderr when stdout is a terminal, as [`Milestone`] lines on stdout with `--format json`, and not at
+/// all while the part is benched or called from elsewhere (e.g. tests).
+use std::io::{stdout, IsTerminal};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::OutputFormat;
+use crate::{Day, Year};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Hidden,
+    Bar,
+    Json,
+}
+
+/// Units of work between two milestones of a spinner.
+pub const SPINNER_STEP: u64 = 100_000;
+
+static MODE: AtomicU8 = AtomicU8::new(Mode::Hidden as u8);
+/// The part whose progress is shown.
+static PART: Mutex<Option<(Year, Day, u8)>> = Mutex::new(None);
+
+/// Shows the progress of a part in `format` until [`hide`] is called.
+pub(crate) fn show(year: Year, day: Day, part: u8, format: OutputFormat) {
+    let mode = match format {
+        OutputFormat::Json => Mode::Json,
+        OutputFormat::Text if stdout().is_terminal() => Mode::Bar,
+        OutputFormat::Text => Mode::Hidden,
+    };
+
+    *PART.lock().unwrap() = Some((year, day, part));
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Hides the progress of the parts that are run from now on.
+pub(crate) fn hide() {
+    MODE.store(Mode::Hidden as u8, Ordering::Relaxed);
+}
+
+fn mode() -> Mode {
+    match MODE.load(Ordering::Relaxed) {
+        x if x == Mode::Bar as u8 => Mode::Bar,
+        x if x == Mode::Json as u8 => Mode::Json,
+        _ => Mode::Hidden,
+    }
+}
+
+/// A step of the progress of a part, printed as `{"progress":{..}}` with `--format json`. Bars log one every tenth
+/// of their total, spinners one every [`SPINNER_STEP`] units of work. Both log one per message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Milestone {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub done: u64,
+    pub total: Option<u64>,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MilestoneLine {
+    progress: Milestone,
+}
+
+/// The milestone printed on a line of output, if any.
+#[must_use]
+pub fn parse_milestone(line: &str) -> Option<Milestone> {
+    serde_json::from_str::<MilestoneLine>(line)
+        .ok()
+        .map(|x| x.progress)
+}
+
+/// Handle to report the work done by a part, e.g.
+///
+/// ```ignore
+/// let progress = Progress::new(cells.len() as u64);
+///
+/// for cell in cells {
+///     answer = answer.max(covered(&board, cell));
+///     progress.inc(1);
+/// }
+/// ```
+///
+/// The handle is cheap to update when progress is hidden, and can be shared by reference across threads. The bar
+/// is cleared when it is dropped.
+pub struct Progress {
+    mode: Mode,
+    bar: Option<ProgressBar>,
+    part: Option<(Year, Day, u8)>,
+    total: Option<u64>,
+    done: AtomicU64,
+    /// Milestones already logged: tenths of the total for bars, steps of [`SPINNER_STEP`] for spinners.
+    logged: AtomicU64,
+}
+
+impl Progress {
+    /// Progress towards `total` units of work, drawn as a bar.
+    #[must_use]
+    pub fn new(total: u64) -> Self {
+        let style = ProgressStyle::with_template(
+            "{spinner} [{elapsed_precise}] {wide_bar} {pos}/{len} ({eta}) {msg}",
+        )
+        .unwrap();
+
+        Self::with_bar(Some(total), || ProgressBar::new(total).with_style(style))
+    }
+
+    /// Progress of an unknown amount of work, drawn as a spinner with the work done and the last message.
+    #[must_use]
+    pub fn spinner() -> Self {
+        let style =
+            ProgressStyle::with_template("{spinner} [{elapsed_precise}] {pos} {msg}").unwrap();
+
+        Self::with_bar(None, || {
+            let bar = ProgressBar::new_spinner().with_style(style);
+            bar.enable_steady_tick(Duration::from_millis(100));
+            bar
+        })
+    }
+
+    fn with_bar(total: Option<u64>, bar: impl FnOnce() -> ProgressBar) -> Self {
+        let mode = mode();
+
+        Self {
+            mode,
+            bar: (mode == Mode::Bar).then(bar),
+            part: *PART.lock().unwrap(),
+            total,
+            done: AtomicU64::new(0),
+            logged: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds `delta` units to the work done.
+    pub fn inc(&self, delta: u64) {
+        if self.mode == Mode::Hidden {
+            return;
+        }
+
+        let done = self.done.fetch_add(delta, Ordering::Relaxed) + delta;
+
+        if let Some(bar) = &self.bar {
+            bar.inc(delta);
+        }
+
+        if self.mode == Mode::Json && self.is_milestone(done) {
+            self.log(done, None);
+        }
+    }
+
+    /// Whether `done` reaches a milestone that was not logged yet, see [`Milestone`].
+    fn is_milestone(&self, done: u64) -> bool {
+        let reached = match self.total {
+            Some(total) => (done * 10 / total.max(1)).min(10),
+            None => done / SPINNER_STEP,
+        };
+
+        self.logged.fetch_max(reached, Ordering::Relaxed) < reached
+    }
+
+    /// Shows `message` next to the progress, e.g. the current step of the part.
+    pub fn message(&self, message: impl Into<String>) {
+        match self.mode {
+            Mode::Hidden => {}
+            Mode::Bar => {
+                if let Some(bar) = &self.bar {
+                    bar.set_message(message.into());
+                }
+            }
+            Mode::Json => self.log(self.done.load(Ordering::Relaxed), Some(message.into())),
+        }
+    }
+
+    fn log(&self, done: u64, message: Option<String>) {
+        let Some((year, day, part)) = self.part else {
+            return;
+        };
+
+        let line = MilestoneLine {
+            progress: Milestone {
+                year,
+                day,
+                part,
+                done,
+                total: self.total,
+                message,
+            },
+        };
+
+        println!("{}", serde_json::to_string(&line).unwrap());
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_milestone, Milestone, Mode, Progress, SPINNER_STEP};
+    use crate::{day, year};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn json_progress(total: Option<u64>) -> Progress {
+        Progress {
+            mode: Mode::Json,
+            bar: None,
+            part: None,
+            total,
+            done: AtomicU64::new(0),
+            logged: AtomicU64::new(0),
+        }
+    }
+
+    /// The work done at every milestone reached by increments of `delta` until `done`.
+    fn milestones(progress: &Progress, delta: u64, done: u64) -> Vec<u64> {
+        let mut milestones = vec![];
+
+        while progress.done.load(Ordering::Relaxed) < done {
+            let logged = progress.logged.load(Ordering::Relaxed);
+            progress.inc(delta);
+
+            if progress.logged.load(Ordering::Relaxed) > logged {
+                milestones.push(progress.done.load(Ordering::Relaxed));
+            }
+        }
+
+        milestones
+    }
+
+    #[test]
+    fn logs_every_tenth_once() {
+        let progress = json_progress(Some(20));
+        assert_eq!(
+            milestones(&progress, 1, 20),
+            [2, 4, 6, 8, 10, 12, 14, 16, 18, 20]
+        );
+
+        // increments beyond the total do not log the last milestone again.
+        progress.inc(5);
+        assert!(!progress.is_milestone(25));
+
+        let progress = json_progress(Some(20));
+        assert_eq!(milestones(&progress, 7, 20), [7, 14, 21]);
+        assert_eq!(progress.logged.load(Ordering::Relaxed), 10);
+    }
+
+    #[test]
+    fn logs_spinner_steps() {
+        let progress = json_progress(None);
+        assert_eq!(
+            milestones(&progress, SPINNER_STEP / 4, 3 * SPINNER_STEP),
+            [SPINNER_STEP, 2 * SPINNER_STEP, 3 * SPINNER_STEP]
+        );
+        assert!(!progress.is_milestone(3 * SPINNER_STEP));
+        assert!(progress.is_milestone(5 * SPINNER_STEP));
+    }
+
+    #[test]
+    fn parses_milestones() {
+        let line =
+            r#"{"progress":{"year":2023,"day":16,"part":2,"done":44,"total":440,"message":null}}"#;
+
+        assert_eq!(
+            parse_milestone(line),
+            Some(Milestone {
+                year: year!(2023),
+                day: day!(16),
+                part: 2,
+                done: 44,
+                total: Some(440),
+                message: None,
+            })
+        );
+        assert_eq!(parse_milestone(r#"{"day":16,"part":2}"#), None);
+    }
+}
@@ -1,8 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::time::Duration;
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::allocations::{format_bytes, Allocations};
+use crate::template::{benchmark_history, get_bin_name, runner::Stats};
+use crate::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -18,26 +21,34 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timings {
+    pub year: Year,
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    pub part_1_memory: Option<Allocations>,
+    pub part_2_memory: Option<Allocations>,
     pub total_nanos: f64,
+    /// Date (`YYYY-MM-DD`) of the run that measured the row, set when the table is updated.
+    pub last_measured: Option<String>,
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a table between two occurrences of `marker`, markers included.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,26 +69,51 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings: &[Timings]) -> String {
     let header = format!("{prefix} Benchmarks");
+    let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // the memory columns are only filled by builds with the `alloc_stats` feature.
+    let has_memory = timings
+        .iter()
+        .any(|x| x.part_1_memory.is_some() || x.part_2_memory.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_memory {
+        lines.push(
+            "| Day | Part 1 | Part 2 | Peak heap | Allocations | Allocated | Last measured |"
+                .into(),
+        );
+        lines.push("| :---: | :---: | :---:  | :---: | :---: | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 | Last measured |".into());
+        lines.push("| :---: | :---: | :---:  | :---: |".into());
+    }
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = get_path_for_bin(timing.year, timing.day);
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_1.as_deref().unwrap_or("-"),
+            timing.part_2.as_deref().unwrap_or("-")
+        );
+
+        if has_memory {
+            let (part_1, part_2) = (timing.part_1_memory, timing.part_2_memory);
+            let peak = memory_cell(part_1, part_2, |x| format_bytes(x.peak_bytes));
+            let count = memory_cell(part_1, part_2, |x| x.count.to_string());
+            let total = memory_cell(part_1, part_2, |x| format_bytes(x.total_bytes));
+            line.push_str(&format!(" {peak} | {count} | {total} |"));
+        }
+
+        line.push_str(&format!(
+            " {} |",
+            timing.last_measured.as_deref().unwrap_or("-")
         ));
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -87,45 +123,253 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+/// A memory metric of both parts, e.g. `` `1.5 MiB` / `3.0 MiB` ``.
+fn memory_cell(
+    part_1: Option<Allocations>,
+    part_2: Option<Allocations>,
+    metric: impl Fn(&Allocations) -> String,
+) -> String {
+    let format = |x: Option<Allocations>| x.as_ref().map_or_else(|| "-".into(), &metric);
+    format!("`{}` / `{}`", format(part_1), format(part_2))
+}
+
+/// Parses the rows of a table written by [`construct_table`] back into [`Timings`]. The stats of the parts
+/// are not part of the table, and byte counts are only as precise as their formatting.
+fn parse_table(table: &str) -> Result<Vec<Timings>, Error> {
+    let mut lines = table.lines().filter(|x| x.starts_with('|'));
+
+    let Some(header) = lines.next() else {
+        return Ok(vec![]);
+    };
+
+    let columns: Vec<&str> = cells(header).collect();
+    let column = |name: &str| columns.iter().position(|&x| x == name);
+
+    // skip the alignment row.
+    lines.next();
+
+    lines
+        .map(|line| {
+            let cells: Vec<&str> = cells(line).collect();
+            let cell = |name: &str| column(name).and_then(|i| cells.get(i)).copied();
+            let invalid = || Error::Parser(format!("Could not parse benchmark row: {line}"));
+
+            let (year, day) = cell("Day").and_then(parse_day_link).ok_or_else(invalid)?;
+            let part_1 = cell("Part 1").and_then(parse_value).map(String::from);
+            let part_2 = cell("Part 2").and_then(parse_value).map(String::from);
+
+            let total_nanos = [&part_1, &part_2]
+                .into_iter()
+                .filter_map(|x| parse_duration(x.as_deref()?))
+                .map(|x| x.as_nanos() as f64)
+                .sum();
+
+            let memory = |part: usize| {
+                let metric = |name: &str, parse: fn(&str) -> Option<u64>| {
+                    let (part_1, part_2) = cell(name)?.split_once(" / ")?;
+                    parse_value([part_1, part_2][part]).and_then(parse)
+                };
+
+                let peak_bytes = metric("Peak heap", parse_bytes);
+                let count = metric("Allocations", |x| x.parse().ok());
+                let total_bytes = metric("Allocated", parse_bytes);
+
+                (peak_bytes.is_some() || count.is_some() || total_bytes.is_some()).then(|| {
+                    Allocations {
+                        peak_bytes: peak_bytes.unwrap_or_default(),
+                        count: count.unwrap_or_default(),
+                        total_bytes: total_bytes.unwrap_or_default(),
+                    }
+                })
+            };
+
+            Ok(Timings {
+                year,
+                day,
+                part_1,
+                part_2,
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: memory(0),
+                part_2_memory: memory(1),
+                total_nanos,
+                last_measured: cell("Last measured")
+                    .and_then(parse_value)
+                    .map(String::from),
+            })
+        })
+        .collect()
+}
+
+/// The trimmed cells of a table row.
+fn cells(line: &str) -> impl Iterator<Item = &str> {
+    let line = line.trim().trim_start_matches('|').trim_end_matches('|');
+    line.split('|').map(str::trim)
+}
+
+/// The year and day of a link to a solution, e.g. `[Day 1](./src/bin/2023-01.rs)`.
+fn parse_day_link(cell: &str) -> Option<(Year, Day)> {
+    let (_, path) = cell.split_once("](")?;
+    let name = path.strip_suffix(".rs)")?.rsplit('/').next()?;
+    let (year, day) = name.split_once('-')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// The content of a cell without its backticks, `None` for a `-` placeholder.
+fn parse_value(cell: &str) -> Option<&str> {
+    let value = cell.trim().trim_matches('`');
+    (!value.is_empty() && value != "-").then_some(value)
+}
+
+/// Parses a duration as formatted by the runner, e.g. `306.0ns` or `1.2s`.
+#[must_use]
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    let nanos = match unit {
+        "ns" => value,
+        "µs" => value * 1e3,
+        "ms" => value * 1e6,
+        "s" => value * 1e9,
+        _ => return None,
+    };
+
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Parses an amount of bytes as formatted by [`format_bytes`].
+fn parse_bytes(s: &str) -> Option<u64> {
+    let (value, unit) = s.split_once(' ')?;
+    let value: f64 = value.parse().ok()?;
+
+    let exponent = ["B", "KiB", "MiB", "GiB", "TiB"]
+        .iter()
+        .position(|&x| x == unit)?;
+
+    Some((value * 1024_f64.powi(exponent as i32)).round() as u64)
+}
+
+/// Whether a part holds a measured duration, rather than nothing or a label like `timed out`.
+fn is_measured(part: Option<&str>) -> bool {
+    part.and_then(parse_duration).is_some()
+}
+
+/// Merges `timings` into the rows of the existing table. Rows of other days are kept, and parts that were not
+/// measured this time (e.g. as the day crashed) keep their previous value and date.
+fn merge(mut rows: Vec<Timings>, timings: Vec<Timings>) -> Vec<Timings> {
+    for timing in timings {
+        match rows
+            .iter_mut()
+            .find(|x| x.year == timing.year && x.day == timing.day)
+        {
+            Some(row) => {
+                let previous = std::mem::replace(row, timing);
+
+                if !is_measured(row.part_1.as_deref()) && previous.part_1.is_some() {
+                    row.part_1 = previous.part_1;
+                    row.part_1_memory = previous.part_1_memory;
+                }
+
+                if !is_measured(row.part_2.as_deref()) && previous.part_2.is_some() {
+                    row.part_2 = previous.part_2;
+                    row.part_2_memory = previous.part_2_memory;
+                }
+
+                if row.last_measured.is_none() {
+                    row.last_measured = previous.last_measured;
+                }
+
+                row.total_nanos = [&row.part_1, &row.part_2]
+                    .into_iter()
+                    .filter_map(|x| parse_duration(x.as_deref()?))
+                    .map(|x| x.as_nanos() as f64)
+                    .sum();
+            }
+            None => rows.push(timing),
+        }
+    }
+
+    rows.sort_by_key(|x| (x.year, x.day));
+    rows
+}
+
+/// Merges `timings` into the table of `s`, returns all rows of the updated table.
+fn update_content(s: &mut String, timings: Vec<Timings>) -> Result<Vec<Timings>, Error> {
+    let positions = locate_table(s, MARKER)?;
+    let rows = parse_table(&s[positions.pos_start..positions.pos_end])?;
+    let rows = merge(rows, timings);
+    let table = construct_table("##", &rows);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+    Ok(rows)
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Merges the `timings` of a run into the benchmarks table of the readme, marking the days with a measured part
+/// as measured today. Returns all rows of the updated table.
+pub fn update(mut timings: Vec<Timings>) -> Result<Vec<Timings>, Error> {
+    let today = benchmark_history::today();
+
+    for timing in &mut timings {
+        if is_measured(timing.part_1.as_deref()) || is_measured(timing.part_2.as_deref()) {
+            timing.last_measured = Some(today.clone());
+        }
+    }
+
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    let rows = update_content(&mut readme, timings)?;
     fs::write(path, &readme)?;
-    Ok(())
+    Ok(rows)
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{construct_table, parse_duration, parse_table, update_content, Timings, MARKER};
+    use crate::template::allocations::Allocations;
+    use crate::template::commands::all::child_commands::{
+        timings_from_run, SolutionRun, Termination,
+    };
+    use crate::{day, year};
+    use std::time::Duration;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
+                year: year!(2023),
                 day: day!(1),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                total_nanos: 3e+7,
+                last_measured: None,
             },
             Timings {
+                year: year!(2023),
                 day: day!(2),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                total_nanos: 7e+7,
+                last_measured: None,
             },
             Timings {
+                year: year!(2023),
                 day: day!(4),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                total_nanos: 9e+7,
+                last_measured: None,
             },
         ]
     }
@@ -134,28 +378,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,18 +407,18 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Part 1 | Part 2 | Last measured |",
+            "| :---: | :---: | :---:  | :---: |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` | - |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | - |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` | - |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +427,108 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings[0].part_1_memory = Some(Allocations {
+            peak_bytes: 1536,
+            count: 12,
+            total_bytes: 3 * 1024 * 1024,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings).unwrap();
+
+        assert!(s.contains(
+            "| Day | Part 1 | Part 2 | Peak heap | Allocations | Allocated | Last measured |"
+        ));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` | `1.5 KiB` / `-` | `12` / `-` | `3.0 MiB` / `-` |"
+        ));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | `-` / `-` |"));
+    }
+
+    #[test]
+    fn parses_constructed_table() {
+        let mut timings = get_mock_timings();
+        timings[1].part_2 = Some("timed out".into());
+        timings[2].part_2 = None;
+        timings[2].last_measured = Some("2023-12-25".into());
+        timings[0].part_2_memory = Some(Allocations {
+            peak_bytes: 1536,
+            count: 12,
+            total_bytes: 3 * 1024 * 1024,
+        });
+
+        let rows = parse_table(&construct_table("##", &timings)).unwrap();
+        timings[1].total_nanos = 3e+7;
+        timings[2].total_nanos = 4e+7;
+        assert_eq!(rows, timings);
+    }
+
+    #[test]
+    fn merges_partial_runs() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings()).unwrap();
+
+        let mut timings = vec![get_mock_timings()[1].clone()];
+        timings[0].part_1 = Some("5ms".into());
+        timings[0].part_2 = None;
+        timings[0].last_measured = Some("2023-12-25".into());
+        let rows = update_content(&mut s, timings).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` | - |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `5ms` | `40ms` | 2023-12-25 |"));
+        assert!(s.contains("**Total: 165.00ms**"));
+    }
+
+    #[test]
+    fn keeps_timings_of_failed_days() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[1].last_measured = Some("2023-12-01".into());
+        update_content(&mut s, timings).unwrap();
+
+        let mut timings = vec![get_mock_timings()[1].clone()];
+        timings[0].part_1 = Some("timed out".into());
+        timings[0].part_2 = Some("crashed".into());
+        update_content(&mut s, timings).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | 2023-12-01 |"));
+
+        let mut timings = vec![get_mock_timings()[2].clone()];
+        timings[0].day = day!(5);
+        timings[0].part_2 = Some("timed out".into());
+        update_content(&mut s, timings).unwrap();
+        assert!(s.contains("| [Day 5](./src/bin/2023-05.rs) | `40ms` | `timed out` | - |"));
+    }
+
+    #[test]
+    fn keeps_skipped_days() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[1].last_measured = Some("2023-12-01".into());
+        update_content(&mut s, timings).unwrap();
+
+        let run = SolutionRun::empty(Termination::Skipped);
+        let timings = timings_from_run(&run, year!(2023), day!(2));
+        assert_eq!(timings, None);
+
+        let rows = update_content(&mut s, timings.into_iter().collect()).unwrap();
+        assert_eq!(rows, {
+            let mut timings = get_mock_timings();
+            timings[1].last_measured = Some("2023-12-01".into());
+            timings
+        });
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | 2023-12-01 |"));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("306.0ns"), Some(Duration::from_nanos(306)));
+        assert_eq!(parse_duration("29.2µs"), Some(Duration::from_nanos(29_200)));
+        assert_eq!(parse_duration("1.2s"), Some(Duration::from_millis(1200)));
+        assert_eq!(parse_duration("timed out"), None);
+    }
 }
@@ -0,0 +1,285 @@
+/// Module that maintains the ⭐️ table of the readme (the one of `advent-readme-stars`) from local state, without
+/// talking to the Advent of Code API. A part earns its star when its answer is confirmed in `data/<year>/answers`
+/// or when its solution returned an answer that does not contradict the confirmed one. Stars already in the table
+/// are kept.
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::template::answers::{self, Verdict};
+use crate::template::readme_benchmarks::{locate_table, Error};
+use crate::template::runner::{Record, Status};
+use crate::{all_days, Day, Year};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The parts of a day that earned a star.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stars {
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl Stars {
+    fn set(&mut self, part: u8) {
+        match part {
+            1 => self.part_1 = true,
+            2 => self.part_2 = true,
+            _ => {}
+        }
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            part_1: self.part_1 || other.part_1,
+            part_2: self.part_2 || other.part_2,
+        }
+    }
+}
+
+/// The stars of a year from its confirmed answers and the solved parts among `records`. A part whose answer differs
+/// from the confirmed one is not solved.
+#[must_use]
+pub fn collect(year: Year, records: &[Record]) -> BTreeMap<Day, Stars> {
+    let mut stars: BTreeMap<Day, Stars> = BTreeMap::new();
+
+    for day in all_days() {
+        let answers = answers::read(year, day).unwrap_or_default();
+
+        for part in [1, 2] {
+            if answers.get(part).is_some() {
+                stars.entry(day).or_default().set(part);
+            }
+        }
+    }
+
+    for record in records {
+        let is_solved =
+            record.status == Status::Solved && !matches!(record.verdict, Verdict::Mismatch { .. });
+
+        if record.year == year && is_solved {
+            stars.entry(record.day).or_default().set(record.part);
+        }
+    }
+
+    stars
+}
+
+fn construct_table(year: Year, stars: &BTreeMap<Day, Stars>) -> String {
+    let star = |x: bool| if x { "⭐" } else { " " };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for (day, stars) in stars {
+        if !stars.part_1 && !stars.part_2 {
+            continue;
+        }
+
+        let day = day.into_inner();
+        lines.push(format!(
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+            star(stars.part_1),
+            star(stars.part_2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+/// The stars of `year` in a table written by [`construct_table`] (or `advent-readme-stars`).
+fn parse_table(year: Year, table: &str) -> BTreeMap<Day, Stars> {
+    let prefix = format!("https://adventofcode.com/{year}/day/");
+
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let link = cells.get(1)?;
+            let (_, day) = link.split_once(&prefix)?;
+            let day: Day = day.strip_suffix(')')?.parse().ok()?;
+
+            let stars = Stars {
+                part_1: cells.get(2)?.contains('⭐'),
+                part_2: cells.get(3)?.contains('⭐'),
+            };
+
+            Some((day, stars))
+        })
+        .collect()
+}
+
+/// The year in the heading of a table, e.g. `## 2023 Results`.
+fn table_year(table: &str) -> Option<Year> {
+    table.lines().find_map(|x| {
+        x.strip_prefix("## ")?
+            .strip_suffix(" Results")?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds `stars` to the table of `s`, returns whether the table changed. A table of another year is only
+/// replaced with `switch_year`.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    stars: BTreeMap<Day, Stars>,
+    switch_year: bool,
+) -> Result<bool, Error> {
+    let positions = locate_table(s, MARKER)?;
+    let current = &s[positions.pos_start..positions.pos_end];
+
+    if !switch_year && table_year(current).is_some_and(|x| x != year) {
+        return Ok(false);
+    }
+
+    let mut merged = parse_table(year, current);
+
+    for (day, stars) in stars {
+        let entry = merged.entry(day).or_default();
+        *entry = entry.union(stars);
+    }
+
+    let table = construct_table(year, &merged);
+
+    if table == current {
+        return Ok(false);
+    }
+
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(true)
+}
+
+/// Rebuilds the ⭐️ table of the readme from the stars of `year` (see [`collect`]), returns whether it changed.
+/// A table of another year is left alone unless `switch_year` is set.
+pub fn update(year: Year, records: &[Record], switch_year: bool) -> Result<bool, Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    let changed = update_content(&mut readme, year, collect(year, records), switch_year)?;
+
+    if changed {
+        fs::write(path, &readme)?;
+    }
+
+    Ok(changed)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collect, update_content, Stars, MARKER};
+    use crate::template::answers::Verdict;
+    use crate::template::runner::{Record, Status};
+    use crate::{day, year};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    fn readme() -> String {
+        [
+            "# readme",
+            MARKER,
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2023/day/2) | ⭐ |   |",
+            MARKER,
+            "",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn keeps_existing_stars() {
+        let mut s = readme();
+        let stars = BTreeMap::from([(
+            day!(1),
+            Stars {
+                part_1: true,
+                part_2: false,
+            },
+        )]);
+
+        assert!(!update_content(&mut s, year!(2023), stars, false).unwrap());
+        assert_eq!(s, readme());
+    }
+
+    #[test]
+    fn adds_new_stars() {
+        let mut s = readme();
+        let stars = BTreeMap::from([
+            (
+                day!(2),
+                Stars {
+                    part_1: true,
+                    part_2: true,
+                },
+            ),
+            (
+                day!(10),
+                Stars {
+                    part_1: true,
+                    part_2: false,
+                },
+            ),
+        ]);
+
+        assert!(update_content(&mut s, year!(2023), stars, false).unwrap());
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2023/day/2) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 10](https://adventofcode.com/2023/day/10) | ⭐ |   |"));
+        assert!(s.starts_with("# readme\n"));
+    }
+
+    #[test]
+    fn skips_wrong_answers() {
+        let record = |part, verdict| Record {
+            year: year!(2015),
+            day: day!(1),
+            part,
+            answer: Some("42".into()),
+            error: None,
+            verdict,
+            status: Status::Solved,
+            duration: Duration::ZERO,
+            samples: 1,
+            stats: None,
+            memory: None,
+        };
+
+        let records = [
+            record(1, Verdict::Missing),
+            record(
+                2,
+                Verdict::Mismatch {
+                    expected: "7".into(),
+                },
+            ),
+        ];
+
+        assert_eq!(
+            collect(year!(2015), &records).get(&day!(1)),
+            Some(&Stars {
+                part_1: true,
+                part_2: false,
+            })
+        );
+    }
+
+    #[test]
+    fn switches_years() {
+        let mut s = readme();
+        assert!(!update_content(&mut s, year!(2022), BTreeMap::new(), false).unwrap());
+        assert_eq!(s, readme());
+
+        assert!(update_content(&mut s, year!(2022), BTreeMap::new(), true).unwrap());
+        assert!(s.contains("## 2022 Results"));
+        assert!(!s.contains("⭐"));
+    }
+}
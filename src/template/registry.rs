@@ -0,0 +1,58 @@
+/// Registry of every solution linked into the current binary.
+/// Each `solution!` invocation submits its parts here, which lets a single binary run any subset of days in-process.
+use crate::template::runner::{self, Record};
+use crate::{Day, Year};
+
+#[doc(hidden)]
+pub use inventory::submit;
+
+/// Both parts of a day, wrapped so they run through [`run_part`](crate::template::runner::run_part).
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub part_one: fn(&str) -> Record,
+    pub part_two: fn(&str) -> Record,
+}
+
+impl Solution {
+    /// Runs the parts selected with `--part` (both by default) on `input`, printing and returning their records.
+    pub fn run(&self, input: &str) -> Vec<Record> {
+        let mut records = vec![];
+
+        if runner::is_part_selected(1) {
+            records.push((self.part_one)(input));
+        }
+
+        if runner::is_part_selected(2) {
+            records.push((self.part_two)(input));
+        }
+
+        records
+    }
+}
+
+inventory::collect!(Solution);
+
+/// All registered solutions, sorted by year and day.
+#[must_use]
+pub fn solutions() -> Vec<&'static Solution> {
+    let mut solutions: Vec<_> = inventory::iter::<Solution>.into_iter().collect();
+    solutions.sort_by_key(|x| (x.year, x.day));
+    solutions
+}
+
+/// The registered solutions of `year`, sorted by day.
+#[must_use]
+pub fn solutions_of(year: Year) -> Vec<&'static Solution> {
+    let mut solutions = solutions();
+    solutions.retain(|x| x.year == year);
+    solutions
+}
+
+/// The registered solution of `day` in `year`, if any.
+#[must_use]
+pub fn find(year: Year, day: Day) -> Option<&'static Solution> {
+    inventory::iter::<Solution>
+        .into_iter()
+        .find(|x| x.year == year && x.day == day)
+}
@@ -0,0 +1,351 @@
+/// Module that renders the benchmark timings as standalone SVG charts, which the readme embeds below the
+/// benchmarks table. The charts are rewritten together with the table (see [`readme_benchmarks::update`]).
+///
+/// [`readme_benchmarks::update`]: crate::template::readme_benchmarks::update
+use std::fmt::Write;
+use std::time::Duration;
+use std::{fs, io};
+
+use crate::template::readme_benchmarks::{parse_duration, Timings};
+
+static CHARTS_DIR: &str = "etc";
+
+const WIDTH_PER_BAR: f64 = 18.0;
+const PLOT_HEIGHT: f64 = 240.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 50.0;
+const MARGIN_RIGHT: f64 = 20.0;
+
+/// Colors of part 1 and part 2 in the bar charts.
+const PART_COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+
+const PALETTE: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+/// Slices of the share chart below this share of the total time are not labeled.
+const MIN_LABELED_SHARE: f64 = 0.03;
+
+/// The measured time of a part, e.g. `16-2` for part 2 of day 16.
+#[derive(Debug, Clone, PartialEq)]
+struct Bar {
+    label: String,
+    part: u8,
+    millis: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scale {
+    Linear,
+    Log,
+}
+
+/// Writes the bar chart, its log-scale variant and the share chart of `timings` to `etc/benchmarks-*.svg`.
+pub fn write(timings: &[Timings]) -> Result<(), io::Error> {
+    let bars = bars(timings);
+    fs::create_dir_all(CHARTS_DIR)?;
+
+    fs::write(
+        format!("{CHARTS_DIR}/benchmarks-time.svg"),
+        bar_chart(&bars, Scale::Linear),
+    )?;
+    fs::write(
+        format!("{CHARTS_DIR}/benchmarks-time-log.svg"),
+        bar_chart(&bars, Scale::Log),
+    )?;
+    fs::write(
+        format!("{CHARTS_DIR}/benchmarks-share.svg"),
+        share_chart(&bars),
+    )?;
+
+    Ok(())
+}
+
+/// The measured parts of `timings`, parts that did not finish show a label instead of a time and are left out.
+fn bars(timings: &[Timings]) -> Vec<Bar> {
+    timings
+        .iter()
+        .flat_map(|timing| {
+            let day = timing.day.into_inner();
+            [(1, &timing.part_1), (2, &timing.part_2)]
+                .into_iter()
+                .filter_map(move |(part, time)| {
+                    parse_duration(time.as_deref()?).map(|duration| Bar {
+                        label: format!("{day}-{part}"),
+                        part,
+                        millis: duration.as_secs_f64() * 1000.0,
+                    })
+                })
+        })
+        .collect()
+}
+
+fn bar_chart(bars: &[Bar], scale: Scale) -> String {
+    let width = MARGIN_LEFT + WIDTH_PER_BAR * bars.len().max(1) as f64 + MARGIN_RIGHT;
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let max = bars.iter().map(|x| x.millis).fold(0.0, f64::max);
+    let min = bars
+        .iter()
+        .map(|x| x.millis)
+        .filter(|&x| x > 0.0)
+        .fold(f64::INFINITY, f64::min);
+
+    let title = match scale {
+        Scale::Linear => "Time per part",
+        Scale::Log => "Time per part (log scale)",
+    };
+
+    // maps a value in ms to its height in the plot, and lists the ticks of the y axis.
+    let (height_of, ticks): (Box<dyn Fn(f64) -> f64>, Vec<f64>) = match scale {
+        Scale::Linear => {
+            let step = nice_step(max / 5.0);
+            let top = (max / step).ceil().max(1.0) * step;
+            let ticks = (0..=(top / step).round() as usize)
+                .map(|i| i as f64 * step)
+                .collect();
+            (Box::new(move |x| x / top * PLOT_HEIGHT), ticks)
+        }
+        Scale::Log => {
+            let (low, high) = log_bounds(min, max);
+            let ticks = (low..=high).map(|x| 10_f64.powi(x)).collect();
+            let span = f64::from(high - low);
+            let height_of = move |x: f64| {
+                let position = (x.max(f64::MIN_POSITIVE).log10() - f64::from(low)) / span;
+                position.clamp(0.0, 1.0) * PLOT_HEIGHT
+            };
+            (Box::new(height_of), ticks)
+        }
+    };
+
+    let bottom = MARGIN_TOP + PLOT_HEIGHT;
+    let mut svg = svg_header(width, height, title);
+
+    for tick in ticks {
+        let y = bottom - height_of(tick);
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="#ddd"/>"##,
+            width - MARGIN_RIGHT
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle">{}</text>"#,
+            MARGIN_LEFT - 6.0,
+            format_millis(tick)
+        );
+    }
+
+    for (i, bar) in bars.iter().enumerate() {
+        let x = MARGIN_LEFT + WIDTH_PER_BAR * i as f64;
+        let bar_height = height_of(bar.millis);
+        let color = PART_COLORS[usize::from(bar.part == 2)];
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{bar_height:.1}" fill="{color}"><title>{} {}</title></rect>"#,
+            x + 2.0,
+            bottom - bar_height,
+            WIDTH_PER_BAR - 4.0,
+            bar.label,
+            format_millis(bar.millis)
+        );
+
+        let label_x = x + WIDTH_PER_BAR / 2.0;
+        let label_y = bottom + 8.0;
+        let _ = writeln!(
+            svg,
+            r#"<text x="{label_x:.1}" y="{label_y:.1}" text-anchor="end" transform="rotate(-60 {label_x:.1} {label_y:.1})">{}</text>"#,
+            bar.label
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// A pie chart of the share of every part in the total time.
+fn share_chart(bars: &[Bar]) -> String {
+    let radius = 130.0;
+    let (width, height) = (520.0, MARGIN_TOP + 2.0 * radius + 60.0);
+    let (cx, cy) = (width / 2.0, MARGIN_TOP + radius + 30.0);
+    let total: f64 = bars.iter().map(|x| x.millis).sum();
+
+    let mut svg = svg_header(width, height, "Share of the total time");
+    let mut angle = -std::f64::consts::FRAC_PI_2;
+
+    for (i, bar) in bars.iter().enumerate() {
+        let share = if total > 0.0 { bar.millis / total } else { 0.0 };
+        let sweep = share * std::f64::consts::TAU;
+        let color = PALETTE[i % PALETTE.len()];
+        let tooltip = format!(
+            "{} {} ({:.1}%)",
+            bar.label,
+            format_millis(bar.millis),
+            share * 100.0
+        );
+
+        if share >= 1.0 {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{cx}" cy="{cy}" r="{radius}" fill="{color}"><title>{tooltip}</title></circle>"#
+            );
+        } else if sweep > 0.0 {
+            let (x0, y0) = (cx + radius * angle.cos(), cy + radius * angle.sin());
+            let end = angle + sweep;
+            let (x1, y1) = (cx + radius * end.cos(), cy + radius * end.sin());
+            let large_arc = u8::from(sweep > std::f64::consts::PI);
+            let _ = writeln!(
+                svg,
+                r#"<path d="M{cx},{cy} L{x0:.2},{y0:.2} A{radius},{radius} 0 {large_arc} 1 {x1:.2},{y1:.2} Z" fill="{color}" stroke="white"><title>{tooltip}</title></path>"#
+            );
+        }
+
+        if share >= MIN_LABELED_SHARE {
+            let middle = angle + sweep / 2.0;
+            let (x, y) = (
+                cx + (radius + 12.0) * middle.cos(),
+                cy + (radius + 12.0) * middle.sin(),
+            );
+            let anchor = if middle.cos() < 0.0 { "end" } else { "start" };
+            let _ = writeln!(
+                svg,
+                r#"<text x="{x:.1}" y="{y:.1}" text-anchor="{anchor}" dominant-baseline="middle">{} ({:.1}%)</text>"#,
+                bar.label,
+                share * 100.0
+            );
+        }
+
+        angle += sweep;
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn svg_header(width: f64, height: f64, title: &str) -> String {
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.0} {height:.0}" font-family="sans-serif" font-size="11">"#,
+            "\n",
+            r#"<rect width="100%" height="100%" fill="white"/>"#,
+            "\n",
+            r#"<text x="{x:.1}" y="24" text-anchor="middle" font-size="15" font-weight="bold">{title}</text>"#,
+            "\n"
+        ),
+        width = width,
+        height = height,
+        x = width / 2.0,
+        title = title
+    )
+}
+
+/// Rounds a step up to 1, 2 or 5 times a power of ten.
+fn nice_step(step: f64) -> f64 {
+    if step <= 0.0 {
+        return 1.0;
+    }
+
+    let magnitude = 10_f64.powf(step.log10().floor());
+    let normalized = step / magnitude;
+
+    let nice = match normalized {
+        x if x <= 1.0 => 1.0,
+        x if x <= 2.0 => 2.0,
+        x if x <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+
+    nice * magnitude
+}
+
+/// The decades (as powers of ten) that enclose `min` and `max`.
+fn log_bounds(min: f64, max: f64) -> (i32, i32) {
+    if !min.is_finite() || max <= 0.0 {
+        return (0, 1);
+    }
+
+    let low = min.log10().floor() as i32;
+    let high = (max.log10().ceil() as i32).max(low + 1);
+    (low, high)
+}
+
+/// Formats an amount of milliseconds like the timings of the runner, e.g. `500µs`.
+fn format_millis(millis: f64) -> String {
+    if millis == 0.0 {
+        return "0".into();
+    }
+
+    let duration = Duration::from_nanos((millis * 1e6).round() as u64);
+    format!("{duration:?}")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bar_chart, bars, log_bounds, nice_step, share_chart, Scale};
+    use crate::template::readme_benchmarks::Timings;
+    use crate::{day, year};
+
+    fn timings() -> Vec<Timings> {
+        vec![
+            Timings {
+                year: year!(2023),
+                day: day!(1),
+                part_1: Some("1.0ms".into()),
+                part_2: Some("3.0ms".into()),
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                total_nanos: 4e+6,
+                last_measured: None,
+            },
+            Timings {
+                year: year!(2023),
+                day: day!(2),
+                part_1: Some("timed out".into()),
+                part_2: Some("timed out".into()),
+                part_1_stats: None,
+                part_2_stats: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                total_nanos: 0.0,
+                last_measured: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn collects_benched_parts() {
+        let bars = bars(&timings());
+        let labels: Vec<&str> = bars.iter().map(|x| x.label.as_str()).collect();
+        assert_eq!(labels, vec!["1-1", "1-2"]);
+        assert_eq!(bars[1].millis, 3.0);
+    }
+
+    #[test]
+    fn rounds_steps() {
+        assert_eq!(nice_step(0.3), 0.5);
+        assert_eq!(nice_step(13.0), 20.0);
+        assert_eq!(nice_step(60.0), 100.0);
+        assert_eq!(log_bounds(0.000016, 1200.0), (-5, 4));
+        assert_eq!(log_bounds(5.0, 5.0), (0, 1));
+    }
+
+    #[test]
+    fn renders_charts() {
+        let bars = bars(&timings());
+
+        let linear = bar_chart(&bars, Scale::Linear);
+        assert_eq!(linear.matches("<rect x=").count(), 2);
+        assert!(linear.contains(">3ms</text>"));
+
+        let log = bar_chart(&bars, Scale::Log);
+        assert!(log.contains(">1ms</text>"));
+        assert!(log.contains(">10ms</text>"));
+
+        let share = share_chart(&bars);
+        assert_eq!(share.matches("<path").count(), 2);
+        assert!(share.contains("1-2 (75.0%)"));
+    }
+}
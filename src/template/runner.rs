@@ -1,63 +1,514 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::allocations::{self, Allocations};
+use crate::template::answers::{self, Verdict};
+use crate::template::aoc_client::AocClient;
+use crate::template::progress;
+use crate::template::submissions::{self, Outcome, Submission};
+use crate::template::{get_data_dir, read_file, read_file_part, ANSI_ITALIC, ANSI_RESET};
+use crate::{recognize, Answer, Day, Year};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub use crate::template::progress::Progress;
+
+/// Return types of solution parts: an [`Option`], `None` being an unsolved part, or a [`Result`] whose error is
+/// reported in place of the answer. Answers are integers, strings or anything else that converts into an
+/// [`Answer`].
+pub trait PartOutput {
+    type Answer: Display + Into<Answer>;
+
+    /// The answer of the part, or the message of its error.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display + Into<Answer>> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+/// The error is formatted with `{:#}`, which errors like `anyhow::Error` use to include their source chain.
+impl<T: Display + Into<Answer>, E: Display> PartOutput for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| format!("{e:#}"))
+    }
+}
+
+pub fn run_part<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) -> Record {
+    let format = output_format();
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    progress::show(year, day, part, format);
+
+    let timed = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        format,
+        |result| {
+            if format == OutputFormat::Text {
+                print_result(
+                    &result.as_ref().ok().and_then(Option::as_ref),
+                    &part_str,
+                    "",
+                );
+            }
+        },
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    progress::hide();
+
+    let (result, duration, stats, memory) = match timed {
+        Ok((result, duration, stats, memory)) => (
+            result.map_err(|e| (Status::Failed, e)),
+            duration,
+            stats,
+            memory,
+        ),
+        Err(panic) => (Err((Status::Panicked, panic)), Duration::ZERO, None, None),
+    };
+
+    let (result, error, status) = match result {
+        Ok(Some(result)) => (Some(result), None, Status::Solved),
+        Ok(None) => (None, None, Status::Unsolved),
+        Err((status, e)) => (None, Some(e), status),
+    };
+
+    let answer = result.as_ref().map(ToString::to_string);
+    let verdict = match answers::read(year, day) {
+        // the confirmed answers belong to the puzzle input only.
+        _ if is_custom_input() => Verdict::Missing,
+        Ok(answers) => answers.verify(part, answer.as_deref()),
+        Err(e) => {
+            eprintln!("Could not read the answers of day {day}: {e}");
+            Verdict::Missing
+        }
+    };
+
+    let record = Record {
+        year,
+        day,
+        part,
+        answer,
+        error,
+        verdict,
+        status,
+        duration,
+        samples: stats.as_ref().map_or(1, |x| x.samples),
+        stats,
+        memory,
+    };
+
+    match format {
+        OutputFormat::Text => print_record(&record),
+        OutputFormat::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result.into(), year, day, part);
+    }
+
+    record
+}
+
+/// How results of solution parts are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable lines, e.g. `Part 1: 42 (1.2µs)`.
+    #[default]
+    Text,
+    /// One [`Record`] per line, serialized as JSON.
+    Json,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => f.write_str("text"),
+            Self::Json => f.write_str("json"),
+        }
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either \"text\" or \"json\"")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The part returned an error (see [`Record::error`]).
+    Failed,
+    /// The part panicked, the error holds the message and location of the panic.
+    Panicked,
+}
+
+/// Outcome of running a solution part. Durations are serialized in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Message of the error returned by the part, or summary of its panic.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Comparison of the answer with the confirmed one in `data/answers`.
+    #[serde(default)]
+    pub verdict: Verdict,
+    pub status: Status,
+    /// Execution time of the single run, or the median when benched.
+    #[serde(with = "nanos")]
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<Stats>,
+    /// Heap usage of the first run, only measured with the `alloc_stats` feature.
+    #[serde(default)]
+    pub memory: Option<Allocations>,
+}
+
+/// Prints a [`Record`] the same way [`run_part`] does in [`OutputFormat::Text`].
+pub fn print_record(record: &Record) {
+    let suffix = format!(
+        "{}{}",
+        format_verdict(&record.verdict),
+        format_duration(
+            &record.duration,
+            record.stats.as_ref(),
+            record.memory.as_ref()
+        )
+    );
+
+    print_result(&record.answer, &format!("Part {}", record.part), &suffix);
+
+    if let Some(error) = &record.error {
+        match record.status {
+            Status::Panicked => println!("{STATS_PREFIX}panicked: {error}"),
+            _ => println!("{STATS_PREFIX}error: {error}"),
+        }
+    }
+
+    if let Some(stats) = &record.stats {
+        println!("{STATS_PREFIX}{}", format_stats(stats));
+    }
+}
+
+/// (De)serializes a [`Duration`] as an integer amount of nanoseconds.
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// Summary of a benchmarked solution part.
+///
+/// The cold first run is kept apart from the warm samples, which are the ones the statistics are computed over.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub cold: Duration,
+    pub warmup: u32,
+    pub samples: u128,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Computes statistics over `timers`. Outliers are samples outside of the Tukey fences (1.5 × IQR).
+    #[must_use]
+    pub fn new(cold: Duration, warmup: u32, timers: &[Duration]) -> Self {
+        let mut sorted = timers.to_vec();
+        sorted.sort_unstable();
+
+        let nanos: Vec<f64> = sorted.iter().map(|x| x.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Self {
+            cold,
+            warmup,
+            samples: sorted.len() as u128,
+            mean: Duration::from_secs_f64(mean / 1e9),
+            median: Duration::from_secs_f64(percentile(&nanos, 50.0) / 1e9),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            p95: Duration::from_secs_f64(percentile(&nanos, 95.0) / 1e9),
+            stddev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            outliers,
+        }
+    }
+}
+
+/// Linear interpolation between the closest ranks of an ascending slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Run a solution part. The behavior differs depending on whether the `--time` flag was passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched after the cold run (see [`bench`]).
+///
+/// The allocations are counted during the first run only (see [`allocations`]). A panic of the first run is
+/// returned as its summary (see [`catch_panic`]), and the part is not benched.
+#[allow(clippy::type_complexity)]
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    format: OutputFormat,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> Result<(T, Duration, Option<Stats>, Option<Allocations>), String> {
+    // clone before counting, the copy of the input is not part of the solution's cost.
+    let cloned = input.clone();
+    allocations::reset();
+
     let timer = Instant::now();
-    let result = func(input.clone());
+    let result = catch_panic(|| func(cloned))?;
     let base_time = timer.elapsed();
 
+    let memory = allocations::read();
+
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if env::args().any(|x| x == "--time") {
+        let stats = bench(func, input, base_time, warmup_iterations(), format);
+        Ok((result, stats.median, Some(stats), memory))
     } else {
-        (base_time, 1)
-    };
+        Ok((result, base_time, None, memory))
+    }
+}
+
+/// Runs `func`, catching a panic as a one-line summary of its message and location, e.g.
+/// `index out of bounds: the len is 3 but the index is 3 (src/bin/2023-06.rs:33:5)`.
+///
+/// While `func` runs, the panic hook of the current thread records the summary instead of printing the message
+/// and backtrace hint to stderr, panics of other threads still go to the previous hook.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    let summary = Arc::new(Mutex::new(None));
+    let previous = Arc::new(panic::take_hook());
+    let thread = thread::current().id();
+
+    panic::set_hook({
+        let (summary, previous) = (Arc::clone(&summary), Arc::clone(&previous));
+        Box::new(move |info| {
+            if thread::current().id() != thread {
+                return previous(info);
+            }
+
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".into());
+            let location = info
+                .location()
+                .map(|x| format!(" ({}:{}:{})", x.file(), x.line(), x.column()))
+                .unwrap_or_default();
+
+            *summary.lock().unwrap() = Some(format!("{message}{location}"));
+        })
+    });
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+
+    // dropping the recording hook releases its handle on the previous one.
+    drop(panic::take_hook());
+    if let Ok(previous) = Arc::try_unwrap(previous) {
+        panic::set_hook(previous);
+    }
 
-    (result, run.0, run.1)
+    result.map_err(|_| {
+        summary
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| "unknown panic".into())
+    })
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Exits with the status of a panicking process (`101`) if one of the parts in `records` panicked, after the
+/// other parts had their run. This keeps CI failing on panics that the runner caught.
+pub fn exit_if_panicked(records: &[Record]) {
+    if records.iter().any(|x| x.status == Status::Panicked) {
+        process::exit(101);
+    }
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Value following the flag `name` in the arguments of the process, if any.
+fn arg_value(name: &str) -> Option<String> {
+    let mut args = env::args().skip_while(|x| x != name);
+    args.next()?;
+    args.next()
+}
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+/// Reads the input of a day: the file passed with `--input <path>` (`-` reads stdin), the example passed
+/// with `--example <n>` (`data/<year>/examples/DD-N.txt`, or `DD.txt` for `1`), or else the puzzle input `data/<year>/inputs/DD.txt`.
+#[must_use]
+pub fn read_input(year: Year, day: Day) -> String {
+    if let Some(path) = arg_value("--input") {
+        let input = if path == "-" {
+            io::read_to_string(io::stdin())
+        } else {
+            std::fs::read_to_string(&path)
+        };
+
+        return input.unwrap_or_else(|e| {
+            eprintln!("Could not read input {path:?}: {e}");
+            process::exit(1);
+        });
+    }
+
+    match arg_value("--example").map(|x| x.parse::<u8>()) {
+        // days with a single example keep it in `DD.txt`.
+        Some(Ok(1))
+            if !get_data_dir("examples", year)
+                .join(format!("{day}-1.txt"))
+                .exists() =>
+        {
+            read_file("examples", year, day)
+        }
+        Some(Ok(n)) => read_file_part("examples", year, day, n),
+        Some(Err(_)) => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --example 2");
+            process::exit(1);
+        }
+        None => read_file("inputs", year, day),
+    }
+}
+
+/// Whether `part` runs, `--part <1|2>` selects a single part.
+#[must_use]
+pub fn is_part_selected(part: u8) -> bool {
+    match arg_value("--part").map(|x| x.parse::<u8>()) {
+        None => true,
+        Some(Ok(selected @ (1 | 2))) => selected == part,
+        Some(_) => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --part 2");
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether the solution runs on anything else than the puzzle input (see [`read_input`]).
+pub(crate) fn is_custom_input() -> bool {
+    env::args().any(|x| x == "--input" || x == "--example")
+}
+
+/// Number of warm-up iterations run before sampling, set with `--warmup <n>`.
+fn warmup_iterations() -> u32 {
+    arg_value("--warmup")
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(DEFAULT_WARMUP)
+}
+
+/// Output format of the results, set with `--format <text|json>`.
+fn output_format() -> OutputFormat {
+    arg_value("--format")
+        .and_then(|x| x.parse().ok())
+        .unwrap_or_default()
+}
+
+const DEFAULT_WARMUP: u32 = 3;
+
+/// Runs `warmup` untimed iterations, then samples the function for approx. 1 second of execution time
+/// (at least 10 and at most 10000 samples), sized after the fastest warm-up run.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: Duration,
+    warmup: u32,
+    format: OutputFormat,
+) -> Stats {
+    // the progress of the part was shown during its first run.
+    progress::hide();
+
+    if format == OutputFormat::Text {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let mut estimate = base_time;
+
+    for _ in 0..warmup {
+        let cloned = input.clone();
+        let timer = Instant::now();
+        func(cloned);
+        estimate = cmp::min(estimate, timer.elapsed());
+    }
+
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(estimate.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -69,26 +520,44 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::new(base_time, warmup, &timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(
+    duration: &Duration,
+    stats: Option<&Stats>,
+    memory: Option<&Allocations>,
+) -> String {
+    let memory = memory.map(|x| format!(" · {x}")).unwrap_or_default();
+
+    match stats {
+        None => format!(" ({duration:.1?}{memory})"),
+        Some(stats) => format!(" ({duration:.1?} @ {} samples{memory})", stats.samples),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+/// Formats the details of a benched part, e.g.
+/// `cold 8.1µs · mean 1.3µs · min 1.1µs · p95 1.4µs · max 3.0µs · σ 120.0ns · 3 outliers · 3 warm-up`.
+#[must_use]
+pub fn format_stats(stats: &Stats) -> String {
+    format!(
+        "cold {:.1?} · mean {:.1?} · min {:.1?} · p95 {:.1?} · max {:.1?} · σ {:.1?} · {} outliers · {} warm-up",
+        stats.cold,
+        stats.mean,
+        stats.min,
+        stats.p95,
+        stats.max,
+        stats.stddev,
+        stats.outliers,
+        stats.warmup
+    )
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Match => " ✔".into(),
+        Verdict::Mismatch { expected } => format!(" ✘ (expected {expected})"),
+        Verdict::Missing => String::new(),
     }
 }
 
@@ -127,18 +596,18 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Prefix of the line that follows a benched part with its [`Stats`].
+pub const STATS_PREFIX: &str = "  ↳ ";
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. the answer was not refused by the submission log (unless `--force` is passed).
+///  3. the Advent of Code client is configured.
+fn submit_result(result: Answer, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -154,14 +623,140 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if is_custom_input() {
+        eprintln!("Not submitting: the answer was computed from --input or --example.");
+        process::exit(1);
+    }
+
+    // a grid displays as its letters once they are recognized, a drawing is never a valid answer.
+    if matches!(&result, Answer::Grid(grid) if recognize(grid).is_none()) {
+        eprintln!("Not submitting: the answer is a drawing whose letters could not be recognized.");
+        process::exit(1);
+    }
+
+    let answer = result.to_string();
+
+    let log = submissions::read(year, day).unwrap_or_else(|e| {
+        eprintln!("Could not read the submission log of day {day}: {e}");
+        process::exit(1);
+    });
+
+    if !args.contains(&"--force".into()) {
+        if let Err(refusal) = submissions::check(&log, part, &answer) {
+            eprintln!("Refusing to submit {answer}: {refusal}. Pass --force to submit anyway.");
+            process::exit(1);
+        }
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let client = AocClient::from_env().unwrap_or_else(|e| {
+        eprintln!("Could not submit: {e}");
         process::exit(1);
+    });
+
+    // the JSON lines on stdout are records only.
+    let report = |message: &str| match output_format() {
+        OutputFormat::Text => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    };
+
+    report("Submitting result...");
+
+    let (outcome, message) = client.submit(year, day, part, &answer).unwrap_or_else(|e| {
+        eprintln!("Could not submit: {e}");
+        process::exit(1);
+    });
+
+    report(&message);
+
+    if let Err(e) = submissions::append(&Submission::new(year, day, part, &answer, outcome)) {
+        eprintln!("Could not log the submission: {e}");
+    }
+
+    if outcome == Outcome::Correct {
+        if let Err(e) = answers::write(year, day, part, &answer) {
+            eprintln!("Could not record the confirmed answer: {e}");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{catch_panic, format_stats, PartOutput, Stats};
+    use std::fmt::Display;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let timers = nanos(&[10, 12, 11, 13, 11, 10, 12, 11, 100]);
+        let stats = Stats::new(Duration::from_nanos(500), 3, &timers);
+
+        assert_eq!(stats.cold.as_nanos(), 500);
+        assert_eq!(stats.samples, 9);
+        assert_eq!(stats.min.as_nanos(), 10);
+        assert_eq!(stats.max.as_nanos(), 100);
+        assert_eq!(stats.median.as_nanos(), 11);
+        assert_eq!(stats.mean.as_nanos(), 21);
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        struct Chain;
+
+        impl Display for Chain {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if f.alternate() {
+                    f.write_str("invalid line 3: invalid digit")
+                } else {
+                    f.write_str("invalid line 3")
+                }
+            }
+        }
+
+        assert_eq!(Some(42).into_answer(), Ok(Some(42)));
+        assert_eq!(None::<u32>.into_answer(), Ok(None));
+        assert_eq!(Ok::<_, String>(42).into_answer(), Ok(Some(42)));
+        assert_eq!(
+            "x".parse::<u32>().into_answer(),
+            Err("invalid digit found in string".into())
+        );
+        assert_eq!(
+            Err::<u32, _>(Chain).into_answer(),
+            Err("invalid line 3: invalid digit".into())
+        );
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let line = line!() + 1;
+        let summary = catch_panic(|| -> u32 { panic!("bad input {}", 3) }).unwrap_err();
+        assert!(summary.starts_with(&format!("bad input 3 ({}:{line}:", file!())));
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::new(Duration::from_nanos(5), 0, &nanos(&[7]));
+        assert_eq!(stats.median, stats.max);
+        assert_eq!(stats.p95.as_nanos(), 7);
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = Stats::new(Duration::from_micros(8), 2, &nanos(&[1000, 1000, 3000]));
+        assert_eq!(
+            format_stats(&stats),
+            "cold 8.0µs · mean 1.7µs · min 1.0µs · p95 2.8µs · max 3.0µs · σ 943.0ns · 0 outliers · 2 warm-up"
+        );
+    }
 }
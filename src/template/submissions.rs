@@ -0,0 +1,250 @@
+/// Module that logs every submitted answer with the verdict of Advent of Code in `data/<year>/submissions/DD.jsonl`,
+/// and uses that log to refuse answers that are known to be wrong before submitting them.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::{Day, Year};
+
+/// Verdict of Advent of Code on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The response could not be recognized.
+    Unknown,
+}
+
+impl Outcome {
+    /// Recognizes the verdict in the response text of a submission.
+    #[must_use]
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Self::Correct
+        } else if response.contains("too high") {
+            Self::TooHigh
+        } else if response.contains("too low") {
+            Self::TooLow
+        } else if response.contains("That's not the right answer") {
+            Self::Wrong
+        } else if response.contains("You gave an answer too recently") {
+            Self::RateLimited
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Whether the answer was rejected, i.e. it is known to be wrong.
+    #[must_use]
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "wrong",
+            Self::RateLimited => "rate-limited",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    #[must_use]
+    pub fn new(year: Year, day: Day, part: u8, answer: &str, outcome: Outcome) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+        }
+    }
+}
+
+/// Reason to refuse the submission of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyRejected { outcome: Outcome },
+    TooHigh { bound: String },
+    TooLow { bound: String },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "part already solved with {answer}"),
+            Refusal::AlreadyRejected { outcome } => {
+                write!(f, "this answer was already rejected ({outcome})")
+            }
+            Refusal::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Refusal::TooLow { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// Checks an answer against the previous submissions of the same part.
+pub fn check(log: &[Submission], part: u8, answer: &str) -> Result<(), Refusal> {
+    let submissions = log.iter().filter(|x| x.part == part);
+
+    for submission in submissions.clone() {
+        if submission.outcome == Outcome::Correct {
+            return Err(Refusal::AlreadySolved {
+                answer: submission.answer.clone(),
+            });
+        }
+
+        if submission.answer == answer && submission.outcome.is_rejection() {
+            return Err(Refusal::AlreadyRejected {
+                outcome: submission.outcome,
+            });
+        }
+    }
+
+    let Ok(value) = answer.parse::<i128>() else {
+        return Ok(());
+    };
+
+    let bounds = submissions.filter_map(|x| Some((x, x.answer.parse::<i128>().ok()?)));
+
+    for (submission, bound) in bounds {
+        match submission.outcome {
+            Outcome::TooHigh if value >= bound => {
+                return Err(Refusal::TooHigh {
+                    bound: submission.answer.clone(),
+                })
+            }
+            Outcome::TooLow if value <= bound => {
+                return Err(Refusal::TooLow {
+                    bound: submission.answer.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn get_log_path(year: Year, day: Day) -> std::path::PathBuf {
+    get_data_path("submissions", year, day).with_extension("jsonl")
+}
+
+/// Reads the submissions of a day, oldest first.
+pub fn read(year: Year, day: Day) -> Result<Vec<Submission>, io::Error> {
+    match fs::read_to_string(get_log_path(year, day)) {
+        Ok(s) => s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| serde_json::from_str(l).map_err(io::Error::from))
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(submission: &Submission) -> Result<(), io::Error> {
+    let path = get_log_path(submission.year, submission.day);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(submission)?)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Outcome, Refusal, Submission};
+    use crate::{day, year};
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(Outcome::parse("That's the right answer! You are one gold star closer to restoring snow operations."), Outcome::Correct);
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer. If you're stuck, ..."),
+            Outcome::Wrong
+        );
+        assert_eq!(Outcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again."), Outcome::RateLimited);
+        assert_eq!(Outcome::parse(""), Outcome::Unknown);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = vec![
+            Submission::new(year!(2023), day!(1), 1, "100", Outcome::TooHigh),
+            Submission::new(year!(2023), day!(1), 1, "10", Outcome::TooLow),
+            Submission::new(year!(2023), day!(1), 1, "50", Outcome::Wrong),
+            Submission::new(year!(2023), day!(1), 1, "abc", Outcome::Wrong),
+            Submission::new(year!(2023), day!(1), 2, "7", Outcome::Correct),
+        ];
+
+        assert_eq!(check(&log, 1, "42"), Ok(()));
+        assert_eq!(check(&log, 1, "xyz"), Ok(()));
+        assert_eq!(
+            check(&log, 1, "50"),
+            Err(Refusal::AlreadyRejected {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(
+            check(&log, 1, "abc"),
+            Err(Refusal::AlreadyRejected {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(
+            check(&log, 1, "120"),
+            Err(Refusal::TooHigh {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            check(&log, 1, "5"),
+            Err(Refusal::TooLow { bound: "10".into() })
+        );
+        assert_eq!(
+            check(&log, 2, "8"),
+            Err(Refusal::AlreadySolved { answer: "7".into() })
+        );
+    }
+}